@@ -1,30 +1,67 @@
-use std::env;
+use std::process;
+use std::sync::Arc;
 
 use clap::Parser;
 use dotenvy::dotenv;
 
-use fi_grafana::api::grafana::GrafanaClient;
-use fi_grafana::cli::shell::request::{Cli, handle_add, handle_del, handle_get, handle_set, RequestMethod};
+use fi_grafana::cli::migrate::run::handle_migrate;
+use fi_grafana::cli::search::get::handle_search;
+use fi_grafana::cli::shell::dry_run::print_dry_run;
+use fi_grafana::cli::shell::request::{Cli, handle_add, handle_del, handle_diff, handle_get, handle_set, RequestMethod};
+use fi_grafana::error::GrafanaCliError;
 
 #[tokio::main]
 async fn main() {
-    dotenv().expect(".env file not found");
+    dotenv().ok();
     let cli = Cli::parse();
-    let api = env::var("GRAFANA_API_PATH").expect("GRAFANA_API_PATH env var not found");
-    let token = env::var("SERVICE_ACCOUNT_TOKEN").expect("SERVICE_ACCOUNT_TOKEN env var not found");
-    let grafana_client = GrafanaClient::new(reqwest::Client::new(), api, token);
-    match cli.method {
+    // a migration writes to the instance of its --to profile, only the profile decides where and as whom
+    let grafana_client = match &cli.method {
+        RequestMethod::Migrate(opt) => cli.client.grafana_client_from_profile_only(&opt.to),
+        _ => cli.client.grafana_client(),
+    };
+    let grafana_client = match grafana_client {
+        Ok(grafana_client) => grafana_client
+            .with_dry_run(cli.dry_run)
+            .with_retry_listener(Some(Arc::new(|retry| eprintln!("{}", retry)))),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(error.exit_code());
+        }
+    };
+    let result = match cli.method {
         RequestMethod::Add(request) => {
-            handle_add(&grafana_client, request).await;
+            handle_add(&grafana_client, request, cli.output).await
         }
         RequestMethod::Get(request) => {
-            handle_get(&grafana_client, request).await;
+            handle_get(&grafana_client, request, cli.output).await
         }
         RequestMethod::Set(request) => {
-            handle_set(&grafana_client, request).await;
+            handle_set(&grafana_client, request, cli.output).await
         }
         RequestMethod::Del(request) => {
-            handle_del(&grafana_client, request).await;
+            handle_del(&grafana_client, request, cli.output).await
+        }
+        RequestMethod::Diff(request) => {
+            handle_diff(&grafana_client, request, cli.output).await
+        }
+        RequestMethod::Migrate(opt) => {
+            handle_migrate(&cli.client, &grafana_client, &opt, cli.output).await
+        }
+        RequestMethod::Search(opt) => {
+            handle_search(&grafana_client, &opt, cli.output).await
+        }
+    };
+    // a dry run ends early but successfully when a request needs the response of an unsent one
+    let result = match result {
+        Err(error @ GrafanaCliError::DryRunResponseRequired(_)) => {
+            cli.output.message(error);
+            Ok(())
         }
+        result => result,
+    };
+    let result = result.and_then(|_| print_dry_run(&grafana_client, cli.output));
+    if let Err(error) = result {
+        eprintln!("{}", error);
+        process::exit(error.exit_code());
     }
 }
@@ -0,0 +1,196 @@
+use std::collections::HashSet;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::api::grafana::GrafanaClient;
+use crate::api::page::DEFAULT_PAGE_SIZE;
+use crate::error::GrafanaCliError;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddAnnotationRequest {
+    /// When dashboard_uid and panel_id are not set, it will be an organizational annotation
+    pub dashboard_uid: Option<String>,
+    /// When dashboard_uid and panel_id are not set, it will be an organizational annotation
+    pub panel_id: Option<u32>,
+    /// Epoch time in millisecond resolution
+    pub time: Option<i64>,
+    /// Epoch time in millisecond resolution (when setting the time_end field, it will be a regional annotation)
+    pub time_end: Option<i64>,
+    /// Tags associated with this annotation
+    pub tags: Vec<String>,
+    /// Description of the annotation
+    pub text: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddAnnotationResponse {
+    /// When the annotation was created the response contains its id
+    pub id: Option<u32>,
+    /// The status message whether the annotation was created or not
+    pub message: String,
+}
+
+/// Only the given fields are changed, the others keep their value
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchAnnotationRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Replaces all tags of the annotation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Epoch time in millisecond resolution
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<i64>,
+    /// Epoch time in millisecond resolution
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_end: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnotationMessageResponse {
+    pub message: String,
+}
+
+/// Distinguishes annotations created by users or the api from the ones created by alert state changes
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum AnnotationType {
+    Annotation,
+    Alert,
+}
+
+/// The filters of an annotation query, all of them are optional
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FindAnnotationsRequest {
+    /// Epoch time in millisecond resolution
+    pub from: Option<i64>,
+    /// Epoch time in millisecond resolution
+    pub to: Option<i64>,
+    pub user_id: Option<u32>,
+    #[serde(rename = "dashboardUID")]
+    pub dashboard_uid: Option<String>,
+    pub panel_id: Option<u32>,
+    pub r#type: Option<AnnotationType>,
+    /// Organizational annotations are found by tags only
+    pub tags: Vec<String>,
+    /// Match any instead of all of the given tags
+    pub match_any: Option<bool>,
+    /// The maximum number of annotations (grafana defaults to 100)
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAnnotationResponse {
+    pub id: u32,
+    pub alert_id: u32,
+    pub dashboard_id: u32,
+    #[serde(rename = "dashboardUID")]
+    pub dashboard_uid: Option<String>,
+    pub panel_id: u32,
+    pub user_id: u32,
+    pub login: String,
+    pub email: String,
+    pub new_state: String,
+    pub prev_state: String,
+    /// Epoch time in millisecond resolution
+    pub created: i64,
+    /// Epoch time in millisecond resolution
+    pub updated: i64,
+    /// Epoch time in millisecond resolution
+    pub time: i64,
+    /// Epoch time in millisecond resolution (equals the time for point annotations)
+    pub time_end: i64,
+    pub text: String,
+    pub tags: Vec<String>,
+}
+
+/// The /api/annotations endpoints
+pub struct Annotations<'a> {
+    client: &'a GrafanaClient,
+}
+
+impl GrafanaClient {
+    pub fn annotations(&self) -> Annotations<'_> {
+        Annotations { client: self }
+    }
+}
+
+impl Annotations<'_> {
+    pub async fn create(&self, request: &AddAnnotationRequest) -> Result<AddAnnotationResponse, GrafanaCliError> {
+        self.client.post("annotations", request).await
+    }
+
+    pub async fn update(&self, id: u32, request: &PatchAnnotationRequest) -> Result<AnnotationMessageResponse, GrafanaCliError> {
+        self.client.patch(&format!("annotations/{}", id), request).await
+    }
+
+    pub async fn delete(&self, id: u32) -> Result<AnnotationMessageResponse, GrafanaCliError> {
+        self.client.del(&format!("annotations/{}", id)).await
+    }
+
+    /// Finds the newest annotations matching the filters up to the limit of the request
+    pub async fn find(&self, request: &FindAnnotationsRequest) -> Result<Vec<GetAnnotationResponse>, GrafanaCliError> {
+        let resource = serde_url_params::to_string(request)?;
+        self.client.get(&format!("annotations?{}", resource)).await
+    }
+
+    /// Finds the newest annotations up to the limit of the request, all annotations if it has none
+    pub async fn find_limited_or_all(&self, request: &FindAnnotationsRequest) -> Result<Vec<GetAnnotationResponse>, GrafanaCliError> {
+        if request.limit.is_some() {
+            self.find(request).await
+        } else {
+            self.find_all(request).await
+        }
+    }
+
+    /// Finds all annotations matching the filters by moving the end of the time range backwards page by page
+    pub async fn find_all(&self, request: &FindAnnotationsRequest) -> Result<Vec<GetAnnotationResponse>, GrafanaCliError> {
+        let mut seen = HashSet::new();
+        let mut annotations = vec![];
+        let mut to = request.to;
+        loop {
+            let page = FindAnnotationsRequest { to, limit: Some(DEFAULT_PAGE_SIZE), ..request.clone() };
+            let batch = self.find(&page).await?;
+            let is_last_page = (batch.len() as u32) < DEFAULT_PAGE_SIZE;
+            let Some(boundary) = batch.iter().map(|annotation| annotation.time).min() else {
+                return Ok(annotations);
+            };
+            let before = annotations.len();
+            // annotations at the boundary time are returned twice and therefore skipped the second time
+            annotations.extend(batch.into_iter().filter(|annotation| seen.insert(annotation.id)));
+            if is_last_page {
+                return Ok(annotations);
+            }
+            if annotations.len() == before {
+                // a full page without new annotations means more of them share the boundary time than fit on a page
+                annotations.extend(self.find_all_at(request, boundary).await?.into_iter().filter(|annotation| seen.insert(annotation.id)));
+                to = Some(boundary - 1);
+            } else {
+                to = Some(boundary);
+            }
+        }
+    }
+
+    /// Finds all annotations at the time at once, the time range can not be split any further so the limit grows instead
+    async fn find_all_at(&self, request: &FindAnnotationsRequest, time: i64) -> Result<Vec<GetAnnotationResponse>, GrafanaCliError> {
+        let mut limit = DEFAULT_PAGE_SIZE.saturating_mul(2);
+        loop {
+            let page = FindAnnotationsRequest { from: Some(time), to: Some(time), limit: Some(limit), ..request.clone() };
+            let batch = self.find(&page).await?;
+            if (batch.len() as u32) < limit {
+                return Ok(batch);
+            }
+            if limit == u32::MAX {
+                return Err(GrafanaCliError::IncompleteAnnotations(time));
+            }
+            limit = limit.saturating_mul(2);
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::api::folder::{GetPermissionResponse, SetFolderPermissionRequest, SetFolderPermissionResponse};
+use crate::api::grafana::GrafanaClient;
+use crate::api::page::DEFAULT_PAGE_SIZE;
+use crate::error::GrafanaCliError;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddDashboardRequest {
+    /// The dashboard schema to create
+    pub dashboard: PostDashboard,
+    /// The folder where the dashboard lives
+    pub folder_uid: Option<String>,
+    /// The reasoning behind the change
+    pub message: String,
+    /// If overwriting existing dashboards with same title or uid is the wish
+    pub overwrite: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostDashboard {
+    /// The incremental id of the grafana instance
+    pub id: Option<u32>,
+    /// The unique identifier across instances
+    pub uid: Option<String>,
+    /// The unique dashboard name within a folder
+    pub title: String,
+    /// The associated search tags
+    pub tags: Vec<String>,
+    /// The desired dashboard timezone
+    pub timezone: String,
+    /// The defined schema version
+    pub schema_version: u16,
+    /// The refresh rate in default seconds
+    pub refresh: String,
+}
+
+/// Saves a complete dashboard model as it was exported, keeping every field the typed model does not know
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveDashboardJsonRequest {
+    /// The complete dashboard model
+    pub dashboard: Value,
+    /// The folder where the dashboard lives
+    pub folder_uid: Option<String>,
+    /// The reasoning behind the change
+    pub message: String,
+    /// If overwriting existing dashboards with same title or uid is the wish
+    pub overwrite: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AddDashboardResponse {
+    pub id: u32,
+    pub uid: String,
+    pub url: String,
+    pub status: String,
+    pub version: u32,
+    pub slug: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDashboardResponse {
+    pub dashboard: GetDashboard,
+    pub meta: GetDashboardMeta,
+}
+
+/// Some conditional fields of the resource are omitted (there are more to work with)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDashboard {
+    /// The incremental id of the grafana instance
+    pub id: u32,
+    /// The unique identifier across instances
+    pub uid: String,
+    /// The unique dashboard name within a folder
+    pub title: String,
+    /// The associated search tags
+    pub tags: Vec<String>,
+    /// The desired dashboard timezone
+    pub timezone: String,
+    /// The defined schema version
+    pub schema_version: u16,
+    /// The dashboard version
+    pub version: u32,
+    /// The available panels of the dashboard
+    pub panels: Option<Vec<GetPanel>>,
+}
+
+/// Some conditional fields of the resource are omitted (there are more to work with)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPanel {
+    pub id: u32,
+    pub title: String,
+    pub r#type: String,
+    pub datasource: GetPanelDataSource,
+    pub grid_pos: GetPanelGridPosition,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPanelDataSource {
+    pub r#type: String,
+    pub uid: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPanelGridPosition {
+    pub h: u16,
+    pub w: u16,
+    pub x: u16,
+    pub y: u16,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDashboardMeta {
+    pub r#type: String,
+    pub can_save: bool,
+    pub can_edit: bool,
+    pub can_admin: bool,
+    pub can_star: bool,
+    pub can_delete: bool,
+    pub slug: String,
+    pub url: String,
+    pub expires: String,
+    pub created: String,
+    pub updated: String,
+    pub updated_by: String,
+    pub created_by: String,
+    pub version: u32,
+    pub has_acl: bool,
+    pub is_folder: bool,
+    pub folder_id: u32,
+    pub folder_uid: String,
+    pub folder_title: String,
+    pub folder_url: String,
+    pub provisioned: bool,
+    pub provisioned_external_id: String,
+    pub annotations_permissions: AnnotationsPermissions,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnotationsPermissions {
+    pub dashboard: DashboardAnnotationPermissions,
+    pub organization: OrganizationAnnotationPermissions,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DashboardAnnotationPermissions {
+    pub can_add: bool,
+    pub can_edit: bool,
+    pub can_delete: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationAnnotationPermissions {
+    pub can_add: bool,
+    pub can_edit: bool,
+    pub can_delete: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteDashboardResponse {
+    pub message: String,
+    pub title: Option<String>,
+}
+
+/// An entry of the version history of a dashboard
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DashboardVersionResponse {
+    pub id: u32,
+    pub version: u32,
+    #[serde(default)]
+    pub parent_version: u32,
+    /// The version this one was restored from, 0 if it was not restored
+    #[serde(default)]
+    pub restored_from: u32,
+    /// RFC 3339 datetime
+    pub created: String,
+    #[serde(default)]
+    pub created_by: String,
+    #[serde(default)]
+    pub message: String,
+}
+
+/// A version of a dashboard together with the dashboard model it saved
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDashboardVersionResponse {
+    #[serde(flatten)]
+    pub version: DashboardVersionResponse,
+    pub data: Value,
+}
+
+/// Older grafana versions answer with the plain list, newer ones wrap it with a continue token
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DashboardVersionsResponse {
+    List(Vec<DashboardVersionResponse>),
+    Page { versions: Vec<DashboardVersionResponse> },
+}
+
+#[derive(Serialize)]
+struct DashboardVersionsParams {
+    limit: u32,
+}
+
+/// The /api/dashboards endpoints
+pub struct Dashboards<'a> {
+    client: &'a GrafanaClient,
+}
+
+impl GrafanaClient {
+    pub fn dashboards(&self) -> Dashboards<'_> {
+        Dashboards { client: self }
+    }
+}
+
+impl Dashboards<'_> {
+    /// Creates a new dashboard or updates an existing one (with overwrite or a matching version)
+    pub async fn save(&self, request: &AddDashboardRequest) -> Result<AddDashboardResponse, GrafanaCliError> {
+        self.client.post("dashboards/db", request).await
+    }
+
+    /// Creates or updates a dashboard from its complete json model
+    pub async fn save_json(&self, request: &SaveDashboardJsonRequest) -> Result<AddDashboardResponse, GrafanaCliError> {
+        self.client.post("dashboards/db", request).await
+    }
+
+    pub async fn get(&self, uid: &str) -> Result<GetDashboardResponse, GrafanaCliError> {
+        self.client.get(&format!("dashboards/uid/{}", uid)).await
+    }
+
+    /// Gets the dashboard and its meta data as raw json, without dropping any field the typed model does not know
+    pub async fn get_json(&self, uid: &str) -> Result<Value, GrafanaCliError> {
+        self.client.get(&format!("dashboards/uid/{}", uid)).await
+    }
+
+    /// Deletes the dashboard (newer grafana versions keep it in the recently deleted dashboards for a while)
+    pub async fn delete(&self, uid: &str) -> Result<DeleteDashboardResponse, GrafanaCliError> {
+        self.client.del(&format!("dashboards/uid/{}", uid)).await
+    }
+
+    /// The uid of the folder of the dashboard, empty for the general folder
+    pub async fn folder_uid(&self, uid: &str) -> Result<String, GrafanaCliError> {
+        let dashboard = self.get_json(uid).await?;
+        Ok(dashboard["meta"]["folderUid"].as_str().unwrap_or_default().to_string())
+    }
+
+    /// The saved versions of the dashboard, the newest first
+    pub async fn versions(&self, uid: &str) -> Result<Vec<DashboardVersionResponse>, GrafanaCliError> {
+        let params = DashboardVersionsParams { limit: DEFAULT_PAGE_SIZE };
+        match self.client.query(&format!("dashboards/uid/{}/versions", uid), &params).await? {
+            DashboardVersionsResponse::List(versions) | DashboardVersionsResponse::Page { versions } => Ok(versions),
+        }
+    }
+
+    pub async fn version(&self, uid: &str, version: u32) -> Result<GetDashboardVersionResponse, GrafanaCliError> {
+        self.client.get(&format!("dashboards/uid/{}/versions/{}", uid, version)).await
+    }
+
+    pub async fn permissions(&self, uid: &str) -> Result<Vec<GetPermissionResponse>, GrafanaCliError> {
+        self.client.get(&format!("dashboards/uid/{}/permissions", uid)).await
+    }
+
+    /// Replaces the access control list of the dashboard, the entries inherited from the folder stay
+    pub async fn set_permissions(&self, uid: &str, request: &SetFolderPermissionRequest) -> Result<SetFolderPermissionResponse, GrafanaCliError> {
+        self.client.post(&format!("dashboards/uid/{}/permissions", uid), request).await
+    }
+}
@@ -0,0 +1,54 @@
+use std::sync::{Arc, Mutex};
+
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::GrafanaCliError;
+
+/// The message of the placeholder response returned for a request that was not sent
+pub const DRY_RUN_MESSAGE: &str = "dry run, request not sent";
+
+/// A mutating request that would have been sent to grafana
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRunRequest {
+    pub method: String,
+    pub url: String,
+    pub body: Option<Value>,
+}
+
+/// Records mutating requests instead of sending them, shared by all clones of a client
+#[derive(Debug, Clone, Default)]
+pub struct DryRun {
+    requests: Arc<Mutex<Vec<DryRunRequest>>>,
+}
+
+impl DryRun {
+    /// Records the request and answers with the request body plus a message,
+    /// which is enough for responses that only carry a message or echo the sent fields
+    pub fn record<T: Serialize + ?Sized, R: DeserializeOwned>(&self, method: Method, url: String, request_data: Option<&T>) -> Result<R, GrafanaCliError> {
+        let body = request_data
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|error| GrafanaCliError::CanNotRenderOutput(error.to_string()))?;
+        let mut response = match &body {
+            Some(Value::Object(fields)) => fields.clone(),
+            _ => serde_json::Map::new(),
+        };
+        response.insert("message".to_string(), Value::String(DRY_RUN_MESSAGE.to_string()));
+        let description = format!("{} {}", method, url);
+        self.requests.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).push(DryRunRequest {
+            method: method.to_string(),
+            url,
+            body,
+        });
+        serde_json::from_value(Value::Object(response)).map_err(|_| GrafanaCliError::DryRunResponseRequired(description))
+    }
+
+    /// All requests recorded so far in the order they would have been sent
+    pub fn requests(&self) -> Vec<DryRunRequest> {
+        self.requests.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+    }
+}
@@ -0,0 +1,336 @@
+use std::collections::HashSet;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::api::grafana::GrafanaClient;
+use crate::api::page::{all_pages, DEFAULT_PAGE_SIZE};
+use crate::error::GrafanaCliError;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddFolderRequest {
+    /// Grafana generates the uid if none is given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+    pub title: String,
+    /// Creates a nested folder below this folder (requires nested folders to be enabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_uid: Option<String>,
+}
+
+/// Renames a folder, grafana rejects the update if the version is not the current one
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateFolderRequest {
+    pub title: String,
+    pub version: u32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveFolderRequest {
+    /// The new parent folder, empty to move the folder to the root level
+    pub parent_uid: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteFolderResponse {
+    pub message: String,
+    pub title: Option<String>,
+}
+
+/// An alert rule as returned by the alerting provisioning api
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertRuleResponse {
+    pub uid: String,
+    pub title: String,
+    #[serde(rename = "folderUID")]
+    pub folder_uid: String,
+    pub rule_group: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddFolderResponse {
+    pub id: u32,
+    pub uid: String,
+    pub title: String,
+    pub url: String,
+    pub has_acl: bool,
+    pub can_save: bool,
+    pub can_edit: bool,
+    pub can_admin: bool,
+    pub created_by: String,
+    pub created: String,
+    pub updated_by: String,
+    pub updated: String,
+    pub version: u32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ListFoldersParams<'a> {
+    limit: u32,
+    page: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_uid: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryFolderResponse {
+    pub id: u32,
+    pub uid: String,
+    pub title: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFolderResponse {
+    pub id: u32,
+    pub uid: String,
+    pub title: String,
+    pub url: String,
+    pub has_acl: bool,
+    pub can_save: bool,
+    pub can_edit: bool,
+    pub can_admin: bool,
+    pub created_by: String,
+    pub created: String,
+    pub updated_by: String,
+    pub updated: String,
+    pub version: u32,
+    // if nested folders are enabled and the folder is nested
+    pub parent_uid: Option<String>,
+    pub parents: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetFolderPermissionRequest {
+    pub items: Vec<FolderPermissionItem>,
+}
+
+/// The permission levels of a folder or dashboard access control list entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PermissionLevel {
+    View,
+    Edit,
+    Admin,
+}
+
+impl PermissionLevel {
+    /// The numeric level as sent to grafana
+    pub fn value(&self) -> u8 {
+        match self {
+            PermissionLevel::View => 1,
+            PermissionLevel::Edit => 2,
+            PermissionLevel::Admin => 4,
+        }
+    }
+
+    /// Renders a numeric level by its name
+    pub fn name(value: u8) -> String {
+        match value {
+            1 => "view".to_string(),
+            2 => "edit".to_string(),
+            4 => "admin".to_string(),
+            other => other.to_string(),
+        }
+    }
+}
+
+/// The basic roles a folder or dashboard permission can be granted to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum BasicRole {
+    Viewer,
+    Editor,
+    Admin,
+}
+
+impl BasicRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BasicRole::Viewer => "Viewer",
+            BasicRole::Editor => "Editor",
+            BasicRole::Admin => "Admin",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FolderPermissionItem {
+    pub role: Option<String>,
+    pub team_id: Option<u32>,
+    pub user_id: Option<u32>,
+    pub permission: u8,
+}
+
+impl FolderPermissionItem {
+    pub fn default_permissions_items() -> Vec<FolderPermissionItem> {
+        let viewer = FolderPermissionItem::role(BasicRole::Viewer.as_str(), PermissionLevel::View.value());
+        let editor = FolderPermissionItem::role(BasicRole::Editor.as_str(), PermissionLevel::Edit.value());
+        let admin = FolderPermissionItem::role(BasicRole::Admin.as_str(), PermissionLevel::Admin.value());
+        vec![viewer, editor, admin]
+    }
+
+    pub fn role(role: &str, permission: u8) -> Self {
+        Self {
+            role: Some(role.to_string()),
+            team_id: None,
+            user_id: None,
+            permission,
+        }
+    }
+
+    pub fn team(team_id: u32, permission: u8) -> Self {
+        Self {
+            role: None,
+            team_id: Some(team_id),
+            user_id: None,
+            permission,
+        }
+    }
+
+    pub fn user(user_id: u32, permission: u8) -> Self {
+        Self {
+            role: None,
+            team_id: None,
+            user_id: Some(user_id),
+            permission,
+        }
+    }
+
+    /// Whether both items grant a permission to the same role, team or user
+    pub fn same_grantee(&self, other: &FolderPermissionItem) -> bool {
+        self.role == other.role && self.team_id == other.team_id && self.user_id == other.user_id
+    }
+}
+
+/// An entry of the access control list of a folder or dashboard
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPermissionResponse {
+    /// 0 if the entry is not granted to a user
+    #[serde(default)]
+    pub user_id: u32,
+    #[serde(default)]
+    pub user_login: String,
+    /// 0 if the entry is not granted to a team
+    #[serde(default)]
+    pub team_id: u32,
+    #[serde(default)]
+    pub team: String,
+    /// Viewer, Editor or Admin if the entry is granted to a basic role
+    pub role: Option<String>,
+    pub permission: u8,
+    #[serde(default)]
+    pub permission_name: String,
+    /// Inherited from a parent folder (can not be changed on this folder or dashboard)
+    #[serde(default)]
+    pub inherited: bool,
+}
+
+impl GetPermissionResponse {
+    /// The entry as it is sent back on an update
+    pub fn item(&self) -> FolderPermissionItem {
+        FolderPermissionItem {
+            role: self.role.clone().filter(|role| !role.is_empty()),
+            team_id: (self.team_id > 0).then_some(self.team_id),
+            user_id: (self.user_id > 0).then_some(self.user_id),
+            permission: self.permission,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetFolderPermissionResponse {
+    pub message: String,
+}
+
+/// The /api/folders endpoints
+pub struct Folders<'a> {
+    client: &'a GrafanaClient,
+}
+
+impl GrafanaClient {
+    pub fn folders(&self) -> Folders<'_> {
+        Folders { client: self }
+    }
+}
+
+impl Folders<'_> {
+    pub async fn create(&self, request: &AddFolderRequest) -> Result<AddFolderResponse, GrafanaCliError> {
+        self.client.post("folders", request).await
+    }
+
+    pub async fn get(&self, uid: &str) -> Result<GetFolderResponse, GrafanaCliError> {
+        self.client.get(&format!("folders/{}", uid)).await
+    }
+
+    pub async fn list(&self, limit: u32, page: u32) -> Result<Vec<QueryFolderResponse>, GrafanaCliError> {
+        self.list_children(None, limit, page).await
+    }
+
+    pub async fn list_all(&self) -> Result<Vec<QueryFolderResponse>, GrafanaCliError> {
+        all_pages(DEFAULT_PAGE_SIZE, |page| self.list(DEFAULT_PAGE_SIZE, page)).await
+    }
+
+    /// Lists one page of the direct subfolders of the parent, without a parent the folders at the root level
+    pub async fn list_children(&self, parent_uid: Option<&str>, limit: u32, page: u32) -> Result<Vec<QueryFolderResponse>, GrafanaCliError> {
+        self.client.query("folders", &ListFoldersParams { limit, page, parent_uid }).await
+    }
+
+    /// Lists the direct subfolders of the parent across all pages
+    pub async fn list_all_children(&self, parent_uid: Option<&str>) -> Result<Vec<QueryFolderResponse>, GrafanaCliError> {
+        all_pages(DEFAULT_PAGE_SIZE, |page| self.list_children(parent_uid, DEFAULT_PAGE_SIZE, page)).await
+    }
+
+    /// The uids of the folders at the root level
+    pub async fn root_uids(&self) -> Result<HashSet<String>, GrafanaCliError> {
+        Ok(self.list_all_children(None).await?.into_iter().map(|folder| folder.uid).collect())
+    }
+
+    /// Lists the direct subfolders of the parent across all pages, leaving out the given root level folders since
+    /// without nested folders grafana ignores the parent and answers with the root level (a subfolder is never on it)
+    pub async fn list_all_subfolders(&self, parent_uid: &str, root_uids: &HashSet<String>) -> Result<Vec<QueryFolderResponse>, GrafanaCliError> {
+        Ok(self.list_all_children(Some(parent_uid)).await?
+            .into_iter()
+            .filter(|subfolder| subfolder.uid != parent_uid && !root_uids.contains(&subfolder.uid))
+            .collect())
+    }
+
+    pub async fn update(&self, uid: &str, request: &UpdateFolderRequest) -> Result<GetFolderResponse, GrafanaCliError> {
+        self.client.put(&format!("folders/{}", uid), request).await
+    }
+
+    /// Moves the folder with its content below another parent (requires nested folders to be enabled)
+    pub async fn move_to(&self, uid: &str, request: &MoveFolderRequest) -> Result<GetFolderResponse, GrafanaCliError> {
+        self.client.post(&format!("folders/{}/move", uid), request).await
+    }
+
+    /// Deletes the folder with its dashboards and subfolders, alert rules block the deletion unless forced
+    pub async fn delete(&self, uid: &str, force_delete_rules: bool) -> Result<DeleteFolderResponse, GrafanaCliError> {
+        self.client.del(&format!("folders/{}?forceDeleteRules={}", uid, force_delete_rules)).await
+    }
+
+    /// The alert rules stored in the folder
+    pub async fn alert_rules(&self, uid: &str) -> Result<Vec<AlertRuleResponse>, GrafanaCliError> {
+        let rules: Vec<AlertRuleResponse> = self.client.get("v1/provisioning/alert-rules").await?;
+        Ok(rules.into_iter().filter(|rule| rule.folder_uid == uid).collect())
+    }
+
+    pub async fn permissions(&self, uid: &str) -> Result<Vec<GetPermissionResponse>, GrafanaCliError> {
+        self.client.get(&format!("folders/{}/permissions", uid)).await
+    }
+
+    /// Replaces the whole access control list of the folder with the given items
+    pub async fn set_permissions(&self, uid: &str, request: &SetFolderPermissionRequest) -> Result<SetFolderPermissionResponse, GrafanaCliError> {
+        self.client.post(&format!("folders/{}/permissions", uid), request).await
+    }
+}
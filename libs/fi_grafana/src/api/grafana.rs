@@ -1,70 +1,191 @@
-use serde::Serialize;
+use reqwest::{Method, RequestBuilder};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 
+use crate::api::dry_run::DryRun;
+use crate::api::retry::{RetryAttempt, RetryListener, RetryPolicy};
+use crate::error::GrafanaCliError;
+
+#[derive(Clone)]
 pub struct GrafanaClient {
     pub client: reqwest::Client,
     pub api: String,
-    pub token: String,
+    pub auth: GrafanaAuth,
+    pub retry: RetryPolicy,
+    /// Sent as X-Grafana-Org-Id header to target an organization other than the default one
+    pub org_id: Option<u32>,
+    /// When set, POST/PUT/PATCH/DELETE requests are recorded instead of sent
+    pub dry_run: Option<DryRun>,
+    /// Notified before a failed request is sent again
+    pub on_retry: Option<RetryListener>,
+}
+
+/// The supported ways to authenticate against grafana
+#[derive(Clone)]
+pub enum GrafanaAuth {
+    /// A service account token (the recommended way)
+    ServiceAccountToken(String),
+    /// A legacy api key (deprecated by grafana in favor of service accounts)
+    ApiKey(String),
+    /// A user login, required by admin-only endpoints like /api/admin/users or /api/orgs
+    Basic { user: String, password: String },
+}
+
+impl GrafanaAuth {
+    fn apply(&self, request: RequestBuilder) -> RequestBuilder {
+        match self {
+            GrafanaAuth::ServiceAccountToken(token) | GrafanaAuth::ApiKey(token) => request.bearer_auth(token),
+            GrafanaAuth::Basic { user, password } => request.basic_auth(user, Some(password)),
+        }
+    }
+}
+
+/// The error body grafana responds with on any non-successful status code
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GrafanaErrorResponse {
+    /// The human-readable reason of the failure
+    pub message: String,
+    /// Some endpoints add a machine-readable status like 'version-mismatch' or 'name-exists'
+    pub status: Option<String>,
 }
 
 impl GrafanaClient {
-    pub fn new(client: reqwest::Client, api: String, token: String) -> Self {
+    pub fn new(client: reqwest::Client, api: String, auth: GrafanaAuth) -> Self {
         Self {
             client,
             api,
-            token,
+            auth,
+            retry: RetryPolicy::default(),
+            org_id: None,
+            dry_run: None,
+            on_retry: None,
         }
     }
 
-    pub async fn post<T: Serialize + ?Sized>(&self, resource: &str, request_data: &T) -> Result<reqwest::Response, reqwest::Error> {
-        Ok(self.client
+    pub fn with_org_id(mut self, org_id: Option<u32>) -> Self {
+        self.org_id = org_id;
+        self
+    }
+
+    /// A client for calls against another organization sharing the connection pool and credentials
+    pub fn in_org(&self, org_id: u32) -> Self {
+        self.clone().with_org_id(Some(org_id))
+    }
+
+    /// Turns on the dry run mode where only reading requests are sent
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run.then(DryRun::default);
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn with_retry_listener(mut self, on_retry: Option<RetryListener>) -> Self {
+        self.on_retry = on_retry;
+        self
+    }
+
+    pub async fn post<T: Serialize + ?Sized, R: DeserializeOwned>(&self, resource: &str, request_data: &T) -> Result<R, GrafanaCliError> {
+        if let Some(dry_run) = &self.dry_run {
+            return dry_run.record(Method::POST, format!("{}{}", self.api, resource), Some(request_data));
+        }
+        let request = self.client
             .post(format!("{}{}", self.api, resource))
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json")
-            .header("Authorization", format!("Bearer {}", self.token))
-            .json(request_data)
-            .send()
-            .await?)
+            .json(request_data);
+        self.send(request).await
     }
 
-    pub async fn get(&self, resource: &str) -> Result<reqwest::Response, reqwest::Error> {
-        Ok(self.client
-            .get(format!("{}{}", self.api, resource))
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json")
-            .header("Authorization", format!("Bearer {}", self.token))
-            .send()
-            .await?)
+    pub async fn get<R: DeserializeOwned>(&self, resource: &str) -> Result<R, GrafanaCliError> {
+        let request = self.client
+            .get(format!("{}{}", self.api, resource));
+        self.send(request).await
     }
 
-    pub async fn put<T: Serialize + ?Sized>(&self, resource: &str, request_data: &T) -> Result<reqwest::Response, reqwest::Error> {
-        Ok(self.client
+    pub async fn put<T: Serialize + ?Sized, R: DeserializeOwned>(&self, resource: &str, request_data: &T) -> Result<R, GrafanaCliError> {
+        if let Some(dry_run) = &self.dry_run {
+            return dry_run.record(Method::PUT, format!("{}{}", self.api, resource), Some(request_data));
+        }
+        let request = self.client
             .put(format!("{}{}", self.api, resource))
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json")
-            .header("Authorization", format!("Bearer {}", self.token))
-            .json(request_data)
-            .send()
-            .await?)
+            .json(request_data);
+        self.send(request).await
     }
 
-    pub async fn del(&self, resource: &str) -> Result<reqwest::Response, reqwest::Error> {
-        Ok(self.client
-            .delete(format!("{}{}", self.api, resource))
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json")
-            .header("Authorization", format!("Bearer {}", self.token))
-            .send()
-            .await?)
+    pub async fn patch<T: Serialize + ?Sized, R: DeserializeOwned>(&self, resource: &str, request_data: &T) -> Result<R, GrafanaCliError> {
+        if let Some(dry_run) = &self.dry_run {
+            return dry_run.record(Method::PATCH, format!("{}{}", self.api, resource), Some(request_data));
+        }
+        let request = self.client
+            .patch(format!("{}{}", self.api, resource))
+            .json(request_data);
+        self.send(request).await
     }
 
-    pub async fn query<T: Serialize + ?Sized>(&self, resource: &str, params: &T) -> Result<reqwest::Response, reqwest::Error> {
-        Ok(self.client
+    pub async fn del<R: DeserializeOwned>(&self, resource: &str) -> Result<R, GrafanaCliError> {
+        if let Some(dry_run) = &self.dry_run {
+            return dry_run.record::<(), R>(Method::DELETE, format!("{}{}", self.api, resource), None);
+        }
+        let request = self.client
+            .delete(format!("{}{}", self.api, resource));
+        self.send(request).await
+    }
+
+    pub async fn query<T: Serialize + ?Sized, R: DeserializeOwned>(&self, resource: &str, params: &T) -> Result<R, GrafanaCliError> {
+        let request = self.client
             .get(format!("{}{}", self.api, resource))
+            .query(params);
+        self.send(request).await
+    }
+
+    async fn send<R: DeserializeOwned>(&self, request: RequestBuilder) -> Result<R, GrafanaCliError> {
+        let mut request = self.auth.apply(request)
             .header("Accept", "application/json")
-            .header("Content-Type", "application/json")
-            .header("Authorization", format!("Bearer {}", self.token))
-            .query(params)
-            .send()
-            .await?)
+            .header("Content-Type", "application/json");
+        if let Some(org_id) = self.org_id {
+            request = request.header("X-Grafana-Org-Id", org_id);
+        }
+        let request = request.build()?;
+        let mut attempt = 1;
+        loop {
+            // a request with a streaming body can not be cloned and is therefore sent only once
+            let Some(current) = request.try_clone().filter(|_| attempt < self.retry.max_attempts) else {
+                let response = self.client.execute(request).await?;
+                return into_result(response).await;
+            };
+            let (reason, delay) = match self.client.execute(current).await {
+                Ok(response) if self.retry.should_retry_status(request.method(), response.status()) => {
+                    (format!("responded with {}", response.status()), self.retry.delay(attempt, Some(&response)))
+                }
+                Ok(response) => return into_result(response).await,
+                Err(error) if self.retry.should_retry_error(request.method(), &error) => {
+                    (format!("failed: {}", error), self.retry.delay(attempt, None))
+                }
+                Err(error) => return Err(GrafanaCliError::Request(error)),
+            };
+            if let Some(on_retry) = &self.on_retry {
+                on_retry(&RetryAttempt { method: request.method().clone(), url: request.url().to_string(), attempt, reason, delay });
+            }
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
     }
-}
\ No newline at end of file
+}
+
+/// Deserializes the body on success, otherwise maps grafana's error body onto a typed error
+async fn into_result<R: DeserializeOwned>(response: reqwest::Response) -> Result<R, GrafanaCliError> {
+    let status = response.status();
+    let body = response.text().await?;
+    if status.is_success() {
+        serde_json::from_str::<R>(&body).map_err(|_| GrafanaCliError::InvalidResponseFormat(body))
+    } else {
+        let error = serde_json::from_str::<GrafanaErrorResponse>(&body).unwrap_or(GrafanaErrorResponse {
+            message: body,
+            status: None,
+        });
+        Err(GrafanaCliError::from_status(status.as_u16(), error))
+    }
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::api::grafana::GrafanaClient;
+use crate::api::page::{all_pages, DEFAULT_PAGE_SIZE};
+use crate::error::GrafanaCliError;
+
+/// The kind of library elements which are panels (2 are variables)
+pub const LIBRARY_PANEL_KIND: u8 = 1;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddLibraryPanelRequest {
+    /// Grafana generates the uid if none is given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+    /// The folder where the library panel lives, none for the general folder
+    pub folder_uid: Option<String>,
+    pub name: String,
+    /// The panel model as it is embedded into the dashboards
+    pub model: Value,
+    pub kind: u8,
+}
+
+/// Replaces the library panel, grafana rejects the update if the version is not the current one
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateLibraryPanelRequest {
+    pub folder_uid: Option<String>,
+    pub name: String,
+    pub model: Value,
+    pub kind: u8,
+    pub version: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLibraryPanelResponse {
+    pub id: u32,
+    pub uid: String,
+    pub name: String,
+    pub kind: u8,
+    /// Empty for the general folder
+    #[serde(default)]
+    pub folder_uid: String,
+    #[serde(default)]
+    pub description: String,
+    pub model: Value,
+    pub version: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchLibraryPanelsResponse {
+    pub total_count: u32,
+    pub elements: Vec<GetLibraryPanelResponse>,
+    pub page: u32,
+    pub per_page: u32,
+}
+
+/// The library elements endpoints wrap every response into a result field
+#[derive(Serialize, Deserialize)]
+pub struct LibraryPanelResult<T> {
+    pub result: T,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SearchLibraryPanelsParams {
+    kind: u8,
+    page: u32,
+    per_page: u32,
+}
+
+/// The /api/library-elements endpoints restricted to library panels
+pub struct LibraryPanels<'a> {
+    client: &'a GrafanaClient,
+}
+
+impl GrafanaClient {
+    pub fn library_panels(&self) -> LibraryPanels<'_> {
+        LibraryPanels { client: self }
+    }
+}
+
+impl LibraryPanels<'_> {
+    pub async fn create(&self, request: &AddLibraryPanelRequest) -> Result<GetLibraryPanelResponse, GrafanaCliError> {
+        let response: LibraryPanelResult<GetLibraryPanelResponse> = self.client.post("library-elements", request).await?;
+        Ok(response.result)
+    }
+
+    pub async fn get(&self, uid: &str) -> Result<GetLibraryPanelResponse, GrafanaCliError> {
+        let response: LibraryPanelResult<GetLibraryPanelResponse> = self.client.get(&format!("library-elements/{}", uid)).await?;
+        Ok(response.result)
+    }
+
+    pub async fn list_page(&self, page: u32, per_page: u32) -> Result<SearchLibraryPanelsResponse, GrafanaCliError> {
+        let params = SearchLibraryPanelsParams { kind: LIBRARY_PANEL_KIND, page, per_page };
+        let response: LibraryPanelResult<SearchLibraryPanelsResponse> = self.client.query("library-elements", &params).await?;
+        Ok(response.result)
+    }
+
+    pub async fn list_all(&self) -> Result<Vec<GetLibraryPanelResponse>, GrafanaCliError> {
+        all_pages(DEFAULT_PAGE_SIZE, |page| async move {
+            Ok(self.list_page(page, DEFAULT_PAGE_SIZE).await?.elements)
+        }).await
+    }
+
+    pub async fn update(&self, uid: &str, request: &UpdateLibraryPanelRequest) -> Result<GetLibraryPanelResponse, GrafanaCliError> {
+        let response: LibraryPanelResult<GetLibraryPanelResponse> = self.client.patch(&format!("library-elements/{}", uid), request).await?;
+        Ok(response.result)
+    }
+}
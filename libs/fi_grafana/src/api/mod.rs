@@ -1 +1,14 @@
-pub mod grafana;
\ No newline at end of file
+pub mod annotation;
+pub mod dashboard;
+pub mod dry_run;
+pub mod folder;
+pub mod grafana;
+pub mod library_panel;
+pub mod page;
+pub mod preferences;
+pub mod retry;
+pub mod role;
+pub mod search;
+pub mod service_account;
+pub mod team;
+pub mod user;
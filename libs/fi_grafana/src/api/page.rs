@@ -0,0 +1,25 @@
+use std::future::Future;
+
+use crate::error::GrafanaCliError;
+
+/// The page size used when walking all pages of an endpoint
+pub const DEFAULT_PAGE_SIZE: u32 = 1000;
+
+/// Fetches page after page (starting with page 1) until a page holds fewer items than the page size
+pub async fn all_pages<T, F, Fut>(per_page: u32, mut fetch_page: F) -> Result<Vec<T>, GrafanaCliError>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<Vec<T>, GrafanaCliError>>,
+{
+    let mut items = vec![];
+    let mut page = 1;
+    loop {
+        let mut batch = fetch_page(page).await?;
+        let is_last_page = batch.is_empty() || (batch.len() as u32) < per_page;
+        items.append(&mut batch);
+        if is_last_page {
+            return Ok(items);
+        }
+        page += 1;
+    }
+}
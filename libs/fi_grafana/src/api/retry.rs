@@ -0,0 +1,109 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Method, Response, StatusCode};
+
+/// A failed attempt of a request which is sent again after the delay
+#[derive(Debug, Clone)]
+pub struct RetryAttempt {
+    pub method: Method,
+    pub url: String,
+    /// The 1-based attempt that just failed
+    pub attempt: u32,
+    /// The status code or the error of the failed attempt
+    pub reason: String,
+    pub delay: Duration,
+}
+
+impl fmt::Display for RetryAttempt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}, retrying in {:?}..", self.method, self.url, self.reason, self.delay)
+    }
+}
+
+/// Gets notified about every retry, the library itself never prints them
+pub type RetryListener = Arc<dyn Fn(&RetryAttempt) + Send + Sync>;
+
+/// Decides if and when a failed request to grafana is sent again
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// The number of attempts including the first one (1 disables retries)
+    pub max_attempts: u32,
+    /// The delay before the first retry which doubles with every further attempt
+    pub initial_backoff: Duration,
+    /// The upper bound of the computed delay between two attempts
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            initial_backoff,
+            max_backoff,
+        }
+    }
+
+    /// Sends every request exactly once
+    pub fn never() -> Self {
+        Self::new(1, Duration::ZERO, Duration::ZERO)
+    }
+
+    /// Too many requests are always retried, server errors only for methods that are safe to repeat
+    pub fn should_retry_status(&self, method: &Method, status: StatusCode) -> bool {
+        match status {
+            StatusCode::TOO_MANY_REQUESTS => true,
+            StatusCode::INTERNAL_SERVER_ERROR | StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT => is_idempotent(method),
+            _ => false,
+        }
+    }
+
+    /// Connection failures and timeouts are only retried for methods that are safe to repeat
+    pub fn should_retry_error(&self, method: &Method, error: &reqwest::Error) -> bool {
+        (error.is_connect() || error.is_timeout()) && is_idempotent(method)
+    }
+
+    /// Exponential backoff with jitter for the given (1-based) attempt that just failed
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self.initial_backoff.saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)));
+        let capped = exponential.min(self.max_backoff);
+        // equal jitter, a random delay between half and the whole capped delay spreads concurrent clients apart
+        let half = capped / 2;
+        let jitter = fastrand::u64(0..=half.as_millis() as u64);
+        half + Duration::from_millis(jitter)
+    }
+
+    /// Prefers the server's Retry-After header (seconds or http-date) over the computed backoff, both capped by the max backoff
+    pub fn delay(&self, attempt: u32, response: Option<&Response>) -> Duration {
+        response
+            .and_then(retry_after)
+            .map(|retry_after| retry_after.min(self.max_backoff))
+            .unwrap_or_else(|| self.backoff(attempt))
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS)
+}
+
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    if let Ok(seconds) = value.trim().parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?.with_timezone(&Utc);
+    (date - Utc::now()).to_std().ok()
+}
@@ -0,0 +1,184 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::grafana::GrafanaClient;
+use crate::error::GrafanaCliError;
+
+/// The prefixes of the roles grafana ships with, all other roles are custom roles
+pub const BUILTIN_ROLE_PREFIXES: [&str; 3] = ["fixed:", "basic:", "managed:"];
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "camelCase")]
+pub struct RolePermission {
+    /// e.g. dashboards:read or folders:write
+    pub action: String,
+    /// e.g. folders:uid:fdu0hhbnheoe8a or dashboards:* (no scope for actions without a scope)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+}
+
+/// A custom role as defined in a yaml file and sent on creation or update
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleDefinition {
+    /// Generated by grafana on creation if missing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+    /// Has to be greater than the current version on update
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Global roles are available in all organizations
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub global: Option<bool>,
+    #[serde(default)]
+    pub permissions: Vec<RolePermission>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRoleResponse {
+    pub uid: String,
+    pub name: String,
+    pub version: u32,
+    pub display_name: Option<String>,
+    pub description: Option<String>,
+    pub group: Option<String>,
+    #[serde(default)]
+    pub global: bool,
+    #[serde(default)]
+    pub hidden: bool,
+    /// Only part of the response when a single role is requested
+    #[serde(default)]
+    pub permissions: Vec<RolePermission>,
+    pub created: Option<String>,
+    pub updated: Option<String>,
+}
+
+impl GetRoleResponse {
+    pub fn is_builtin(&self) -> bool {
+        BUILTIN_ROLE_PREFIXES.iter().any(|prefix| self.name.starts_with(prefix))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AssignRoleRequest<'a> {
+    role_uid: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SearchUserPermissionsParams {
+    user_id: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleMessageResponse {
+    pub message: String,
+}
+
+/// Who a role is assigned to, service accounts are assigned like users
+#[derive(Debug, Clone, Copy)]
+pub enum RoleAssignee {
+    User(u32),
+    Team(u32),
+    ServiceAccount(u32),
+}
+
+impl RoleAssignee {
+    fn resource(&self) -> String {
+        match self {
+            RoleAssignee::User(id) | RoleAssignee::ServiceAccount(id) => format!("access-control/users/{}/roles", id),
+            RoleAssignee::Team(id) => format!("access-control/teams/{}/roles", id),
+        }
+    }
+}
+
+impl Display for RoleAssignee {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoleAssignee::User(id) => write!(f, "user {}", id),
+            RoleAssignee::Team(id) => write!(f, "team {}", id),
+            RoleAssignee::ServiceAccount(id) => write!(f, "service account {}", id),
+        }
+    }
+}
+
+/// The /api/access-control endpoints (role based access control)
+pub struct Roles<'a> {
+    client: &'a GrafanaClient,
+}
+
+impl GrafanaClient {
+    pub fn roles(&self) -> Roles<'_> {
+        Roles { client: self }
+    }
+}
+
+impl Roles<'_> {
+    /// Lists the built-in and custom roles without their permissions
+    pub async fn list(&self) -> Result<Vec<GetRoleResponse>, GrafanaCliError> {
+        self.client.get("access-control/roles").await
+    }
+
+    pub async fn get(&self, uid: &str) -> Result<GetRoleResponse, GrafanaCliError> {
+        self.client.get(&format!("access-control/roles/{}", uid)).await
+    }
+
+    pub async fn create(&self, role: &RoleDefinition) -> Result<GetRoleResponse, GrafanaCliError> {
+        self.client.post("access-control/roles", role).await
+    }
+
+    /// Replaces the role with the given definition
+    pub async fn update(&self, uid: &str, role: &RoleDefinition) -> Result<GetRoleResponse, GrafanaCliError> {
+        self.client.put(&format!("access-control/roles/{}", uid), role).await
+    }
+
+    /// Deletes the role, force also removes its assignments
+    pub async fn delete(&self, uid: &str, force: bool) -> Result<RoleMessageResponse, GrafanaCliError> {
+        self.client.del(&format!("access-control/roles/{}?force={}", uid, force)).await
+    }
+
+    pub async fn assigned(&self, assignee: RoleAssignee) -> Result<Vec<GetRoleResponse>, GrafanaCliError> {
+        self.client.get(&assignee.resource()).await
+    }
+
+    pub async fn assign(&self, assignee: RoleAssignee, uid: &str) -> Result<RoleMessageResponse, GrafanaCliError> {
+        self.client.post(&assignee.resource(), &AssignRoleRequest { role_uid: uid }).await
+    }
+
+    pub async fn unassign(&self, assignee: RoleAssignee, uid: &str) -> Result<RoleMessageResponse, GrafanaCliError> {
+        self.client.del(&format!("{}/{}", assignee.resource(), uid)).await
+    }
+
+    /// The permissions a user has through its basic role, teams and role assignments
+    pub async fn user_permissions(&self, user_id: u32) -> Result<Vec<RolePermission>, GrafanaCliError> {
+        let response: HashMap<String, BTreeMap<String, Vec<String>>> = self.client
+            .query("access-control/users/permissions/search", &SearchUserPermissionsParams { user_id })
+            .await?;
+        let mut permissions: Vec<RolePermission> = response
+            .into_values()
+            .flatten()
+            .flat_map(|(action, scopes)| {
+                if scopes.is_empty() {
+                    vec![RolePermission { action, scope: None }]
+                } else {
+                    scopes.into_iter().map(|scope| RolePermission { action: action.clone(), scope: Some(scope).filter(|scope| !scope.is_empty()) }).collect()
+                }
+            })
+            .collect();
+        permissions.sort();
+        permissions.dedup();
+        Ok(permissions)
+    }
+}
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::grafana::GrafanaClient;
+use crate::api::page::{all_pages, DEFAULT_PAGE_SIZE};
+use crate::error::GrafanaCliError;
+
+pub const DASH_DB_TYPE: &str = "dash-db";
+pub const DASH_FOLDER_TYPE: &str = "dash-folder";
+
+pub const TIME_SERIES_PANEL_TYPE: &str = "timeseries";
+
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchDashTypeRequest {
+    pub r#type: Option<String>,
+    pub query: Option<String>,
+    #[serde(rename = "folderUIDs")]
+    pub folder_uids: Option<Vec<String>>,
+    #[serde(rename = "dashboardUIDs")]
+    pub dashboard_uids: Option<Vec<String>>,
+    /// Only matches carrying all of the tags
+    pub tag: Option<Vec<String>>,
+    /// Only the dashboards starred by the current user
+    pub starred: Option<bool>,
+    /// alpha-asc or alpha-desc, grafana sorts by relevance without it
+    pub sort: Option<String>,
+    /// Only the recently deleted dashboards which can still be restored
+    pub deleted: Option<bool>,
+    /// The page size (grafana defaults to 1000 and allows up to 5000)
+    pub limit: Option<u32>,
+    /// The 1-based page to fetch
+    pub page: Option<u32>,
+}
+
+impl SearchDashTypeRequest {
+    pub fn type_query(r#type: String, query: String) -> Self {
+        Self {
+            r#type: Some(r#type),
+            query: Some(query.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DashTypeResponse {
+    pub id: u32,
+    pub uid: String,
+    pub title: String,
+    pub uri: String,
+    pub url: String,
+    pub slug: String,
+    pub r#type: String,
+    pub tags: Vec<String>,
+    pub is_starred: bool,
+    pub sort_meta: u16,
+    /// This field is only present when the dash-folder or dash-db type is nested within another folder
+    pub folder_id: Option<u32>,
+    /// This field is only present when the dash-folder or dash-db type is nested within another folder
+    pub folder_uid: Option<String>,
+    /// This field is only present when the dash-folder or dash-db type is nested within another folder
+    pub folder_title: Option<String>,
+    /// This field is only present when the dash-folder or dash-db type is nested within another folder
+    pub folder_url: Option<String>,
+}
+
+/// The /api/search endpoint for dashboards and folders
+pub struct Search<'a> {
+    client: &'a GrafanaClient,
+}
+
+impl GrafanaClient {
+    pub fn search(&self) -> Search<'_> {
+        Search { client: self }
+    }
+}
+
+impl Search<'_> {
+    /// Fetches the single page (limit and page) the request asks for
+    pub async fn dash_types(&self, request: &SearchDashTypeRequest) -> Result<Vec<DashTypeResponse>, GrafanaCliError> {
+        let resource = serde_url_params::to_string(request)?;
+        self.client.get(&format!("search?{}", resource)).await
+    }
+
+    /// Fetches all pages ignoring the limit and page of the request
+    pub async fn dash_types_all(&self, request: &SearchDashTypeRequest) -> Result<Vec<DashTypeResponse>, GrafanaCliError> {
+        all_pages(DEFAULT_PAGE_SIZE, |page| {
+            let request = SearchDashTypeRequest { limit: Some(DEFAULT_PAGE_SIZE), page: Some(page), ..request.clone() };
+            async move { self.dash_types(&request).await }
+        }).await
+    }
+
+    pub async fn dash_type_uids(&self, request: &SearchDashTypeRequest) -> Result<Vec<String>, GrafanaCliError> {
+        Ok(self.dash_types_all(request).await?.iter().map(|dash_type| dash_type.uid.clone()).collect())
+    }
+}
@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::grafana::GrafanaClient;
+use crate::api::page::{all_pages, DEFAULT_PAGE_SIZE};
+use crate::api::user::OrgRole;
+use crate::error::GrafanaCliError;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddServiceAccountRequest {
+    pub name: String,
+    pub role: Option<OrgRole>,
+    pub is_disabled: bool,
+}
+
+/// Only the given fields are changed, the others keep their value
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateServiceAccountRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<OrgRole>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_disabled: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetServiceAccountResponse {
+    pub id: u32,
+    pub name: String,
+    pub login: String,
+    pub org_id: u32,
+    pub is_disabled: bool,
+    pub role: String,
+    /// The number of tokens of the service account
+    pub tokens: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchServiceAccountsResponse {
+    pub total_count: u32,
+    pub service_accounts: Vec<GetServiceAccountResponse>,
+    pub page: u32,
+    pub per_page: u32,
+}
+
+#[derive(Serialize)]
+struct SearchServiceAccountsParams<'a> {
+    query: Option<&'a str>,
+    page: u32,
+    perpage: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceAccountMessageResponse {
+    pub message: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddTokenRequest {
+    pub name: String,
+    /// The token never expires without a time to live
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seconds_to_live: Option<u64>,
+}
+
+/// The key of a token is only part of the response to its creation
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddTokenResponse {
+    pub id: u32,
+    pub name: String,
+    pub key: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTokenResponse {
+    pub id: u32,
+    pub name: String,
+    /// RFC 3339 datetime
+    pub created: Option<String>,
+    /// RFC 3339 datetime, none if the token never expires
+    pub expiration: Option<String>,
+    /// RFC 3339 datetime, none if the token was never used
+    pub last_used_at: Option<String>,
+    pub has_expired: bool,
+    #[serde(default)]
+    pub is_revoked: bool,
+}
+
+/// The /api/serviceaccounts endpoints
+pub struct ServiceAccounts<'a> {
+    client: &'a GrafanaClient,
+}
+
+impl GrafanaClient {
+    pub fn service_accounts(&self) -> ServiceAccounts<'_> {
+        ServiceAccounts { client: self }
+    }
+}
+
+impl ServiceAccounts<'_> {
+    pub async fn create(&self, request: &AddServiceAccountRequest) -> Result<GetServiceAccountResponse, GrafanaCliError> {
+        self.client.post("serviceaccounts", request).await
+    }
+
+    pub async fn get(&self, id: u32) -> Result<GetServiceAccountResponse, GrafanaCliError> {
+        self.client.get(&format!("serviceaccounts/{}", id)).await
+    }
+
+    /// Searches one page of service accounts by name, without a query all service accounts are in scope
+    pub async fn search_page(&self, query: Option<&str>, page: u32, per_page: u32) -> Result<SearchServiceAccountsResponse, GrafanaCliError> {
+        self.client.query("serviceaccounts/search", &SearchServiceAccountsParams { query, page, perpage: per_page }).await
+    }
+
+    /// Searches service accounts by name across all pages
+    pub async fn search_all(&self, query: Option<&str>) -> Result<Vec<GetServiceAccountResponse>, GrafanaCliError> {
+        all_pages(DEFAULT_PAGE_SIZE, |page| async move {
+            Ok(self.search_page(query, page, DEFAULT_PAGE_SIZE).await?.service_accounts)
+        }).await
+    }
+
+    pub async fn update(&self, id: u32, request: &UpdateServiceAccountRequest) -> Result<ServiceAccountMessageResponse, GrafanaCliError> {
+        self.client.patch(&format!("serviceaccounts/{}", id), request).await
+    }
+
+    /// Deletes the service account together with all of its tokens
+    pub async fn delete(&self, id: u32) -> Result<ServiceAccountMessageResponse, GrafanaCliError> {
+        self.client.del(&format!("serviceaccounts/{}", id)).await
+    }
+
+    pub async fn tokens(&self, id: u32) -> Result<Vec<GetTokenResponse>, GrafanaCliError> {
+        self.client.get(&format!("serviceaccounts/{}/tokens", id)).await
+    }
+
+    pub async fn create_token(&self, id: u32, request: &AddTokenRequest) -> Result<AddTokenResponse, GrafanaCliError> {
+        self.client.post(&format!("serviceaccounts/{}/tokens", id), request).await
+    }
+
+    pub async fn delete_token(&self, id: u32, token_id: u32) -> Result<ServiceAccountMessageResponse, GrafanaCliError> {
+        self.client.del(&format!("serviceaccounts/{}/tokens/{}", id, token_id)).await
+    }
+}
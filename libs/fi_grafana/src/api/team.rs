@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::grafana::GrafanaClient;
+use crate::api::page::{all_pages, DEFAULT_PAGE_SIZE};
+use crate::api::preferences::{Preferences, PreferencesResponse};
+use crate::error::GrafanaCliError;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddTeamRequest {
+    pub name: String,
+    pub email: Option<String>,
+    pub org_id: Option<u32>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTeamRequest {
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTeamResponse {
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddTeamResponse {
+    pub message: String,
+    pub team_id: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchTeamsResponse {
+    pub total_count: u32,
+    pub teams: Vec<GetTeamResponse>,
+    pub page: u32,
+    pub per_page: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTeamResponse {
+    pub id: u32,
+    pub org_id: u32,
+    pub name: String,
+    pub email: String,
+    pub avatar_url: String,
+    pub member_count: u32,
+}
+
+#[derive(Serialize)]
+struct SearchTeamsParams<'a> {
+    query: Option<&'a str>,
+    page: u32,
+    perpage: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteTeamResponse {
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTeamMemberResponse {
+    pub org_id: u32,
+    pub team_id: u32,
+    pub user_id: u32,
+    pub email: String,
+    pub name: String,
+    pub login: String,
+    pub avatar_url: String,
+    /// Member = 0, Admin = 4
+    pub permission: u8,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddTeamMemberRequest {
+    pub user_id: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMemberResponse {
+    pub message: String,
+}
+
+/// The /api/teams endpoints
+pub struct Teams<'a> {
+    client: &'a GrafanaClient,
+}
+
+impl GrafanaClient {
+    pub fn teams(&self) -> Teams<'_> {
+        Teams { client: self }
+    }
+}
+
+impl Teams<'_> {
+    pub async fn create(&self, request: &AddTeamRequest) -> Result<AddTeamResponse, GrafanaCliError> {
+        self.client.post("teams", request).await
+    }
+
+    pub async fn get(&self, team_id: u32) -> Result<GetTeamResponse, GrafanaCliError> {
+        self.client.get(&format!("teams/{}", team_id)).await
+    }
+
+    /// Searches one page of teams by name, without a query all teams are in scope
+    pub async fn search_page(&self, query: Option<&str>, page: u32, per_page: u32) -> Result<SearchTeamsResponse, GrafanaCliError> {
+        self.client.query("teams/search", &SearchTeamsParams { query, page, perpage: per_page }).await
+    }
+
+    /// Searches teams by name across all pages
+    pub async fn search_all(&self, query: Option<&str>) -> Result<Vec<GetTeamResponse>, GrafanaCliError> {
+        all_pages(DEFAULT_PAGE_SIZE, |page| async move {
+            Ok(self.search_page(query, page, DEFAULT_PAGE_SIZE).await?.teams)
+        }).await
+    }
+
+    /// Renames the team or changes its email, both fields are always replaced
+    pub async fn update(&self, team_id: u32, request: &UpdateTeamRequest) -> Result<UpdateTeamResponse, GrafanaCliError> {
+        self.client.put(&format!("teams/{}", team_id), request).await
+    }
+
+    pub async fn preferences(&self, team_id: u32) -> Result<Preferences, GrafanaCliError> {
+        self.client.get(&format!("teams/{}/preferences", team_id)).await
+    }
+
+    /// Replaces all preferences of the team, fields left empty fall back to the organization preferences
+    pub async fn set_preferences(&self, team_id: u32, preferences: &Preferences) -> Result<PreferencesResponse, GrafanaCliError> {
+        self.client.put(&format!("teams/{}/preferences", team_id), preferences).await
+    }
+
+    /// Resolves a team id or exact team name to the team id (a number is taken as id as it is)
+    pub async fn resolve_id(&self, id_or_name: &str) -> Result<u32, GrafanaCliError> {
+        if let Ok(team_id) = id_or_name.parse::<u32>() {
+            return Ok(team_id);
+        }
+        self.search_all(Some(id_or_name)).await?
+            .into_iter()
+            .find(|team| team.name == id_or_name)
+            .map(|team| team.id)
+            .ok_or(GrafanaCliError::TeamNotFound(id_or_name.to_string()))
+    }
+
+    pub async fn delete(&self, team_id: u32) -> Result<DeleteTeamResponse, GrafanaCliError> {
+        self.client.del(&format!("teams/{}", team_id)).await
+    }
+
+    pub async fn members(&self, team_id: u32) -> Result<Vec<GetTeamMemberResponse>, GrafanaCliError> {
+        self.client.get(&format!("teams/{}/members", team_id)).await
+    }
+
+    pub async fn add_member(&self, team_id: u32, user_id: u32) -> Result<TeamMemberResponse, GrafanaCliError> {
+        self.client.post(&format!("teams/{}/members", team_id), &AddTeamMemberRequest { user_id }).await
+    }
+
+    pub async fn remove_member(&self, team_id: u32, user_id: u32) -> Result<TeamMemberResponse, GrafanaCliError> {
+        self.client.del(&format!("teams/{}/members/{}", team_id, user_id)).await
+    }
+}
@@ -0,0 +1,214 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::api::grafana::GrafanaClient;
+use crate::api::page::{all_pages, DEFAULT_PAGE_SIZE};
+use crate::api::team::GetTeamResponse;
+use crate::error::GrafanaCliError;
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchUsersResponse {
+    pub total_count: u32,
+    pub users: Vec<SearchUserResponse>,
+    pub page: u32,
+    pub per_page: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchUserResponse {
+    pub id: u32,
+    pub name: String,
+    pub login: String,
+    pub email: String,
+    pub is_admin: bool,
+    pub is_disabled: bool,
+    pub last_seen_at_age: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetUserResponse {
+    pub id: u32,
+    pub email: String,
+    pub name: String,
+    pub login: String,
+    pub org_id: u32,
+    pub is_grafana_admin: bool,
+    pub is_disabled: bool,
+    pub is_external: bool,
+    pub updated_at: String,
+    pub created_at: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddUserRequest {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub login: Option<String>,
+    pub password: String,
+    #[serde(rename = "OrgId", skip_serializing_if = "Option::is_none")]
+    pub org_id: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddUserResponse {
+    pub id: u32,
+    pub message: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateUserRequest {
+    pub name: String,
+    pub email: String,
+    pub login: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GrafanaAdminRequest {
+    is_grafana_admin: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OrgRoleRequest {
+    role: OrgRole,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserMessageResponse {
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOrgResponse {
+    pub org_id: u32,
+    pub name: String,
+    pub role: String,
+}
+
+/// The basic role of a user within an organization
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OrgRole {
+    Viewer,
+    Editor,
+    Admin,
+    /// No basic role, permissions come from teams and role assignments only
+    None,
+}
+
+#[derive(Serialize)]
+struct SearchUsersParams<'a> {
+    query: Option<&'a str>,
+    page: u32,
+    perpage: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LookupUserParams<'a> {
+    login_or_email: &'a str,
+}
+
+/// The /api/users, /api/admin/users and /api/org/users endpoints (require basic auth or the users:read/write permissions)
+pub struct Users<'a> {
+    client: &'a GrafanaClient,
+}
+
+impl GrafanaClient {
+    pub fn users(&self) -> Users<'_> {
+        Users { client: self }
+    }
+}
+
+impl Users<'_> {
+    /// Searches one page of users by login, email or name
+    pub async fn search_page(&self, query: Option<&str>, page: u32, per_page: u32) -> Result<SearchUsersResponse, GrafanaCliError> {
+        self.client.query("users/search", &SearchUsersParams { query, page, perpage: per_page }).await
+    }
+
+    /// Searches users by login, email or name across all pages
+    pub async fn search_all(&self, query: Option<&str>) -> Result<Vec<SearchUserResponse>, GrafanaCliError> {
+        all_pages(DEFAULT_PAGE_SIZE, |page| async move {
+            Ok(self.search_page(query, page, DEFAULT_PAGE_SIZE).await?.users)
+        }).await
+    }
+
+    /// Finds the user with exactly this login or email
+    pub async fn lookup(&self, login_or_email: &str) -> Result<GetUserResponse, GrafanaCliError> {
+        self.client.query("users/lookup", &LookupUserParams { login_or_email }).await
+    }
+
+    /// Resolves a user id, login or email to the user id
+    pub async fn resolve_id(&self, id_login_or_email: &str) -> Result<u32, GrafanaCliError> {
+        Ok(self.find(id_login_or_email).await?.id)
+    }
+
+    pub async fn get(&self, user_id: u32) -> Result<GetUserResponse, GrafanaCliError> {
+        self.client.get(&format!("users/{}", user_id)).await
+    }
+
+    /// Gets the user (or service account) the client is authenticated as
+    pub async fn current(&self) -> Result<GetUserResponse, GrafanaCliError> {
+        self.client.get("user").await
+    }
+
+    /// Gets the user by its id, login or email, a number is tried as id first and then as login (e.g. an employee number)
+    pub async fn find(&self, id_login_or_email: &str) -> Result<GetUserResponse, GrafanaCliError> {
+        match id_login_or_email.parse::<u32>() {
+            Ok(user_id) => match self.get(user_id).await {
+                Err(GrafanaCliError::NotFound(_)) => self.lookup(id_login_or_email).await,
+                result => result,
+            },
+            Err(_) => self.lookup(id_login_or_email).await,
+        }
+    }
+
+    /// Creates a user with the admin api (basic auth of a grafana admin required)
+    pub async fn create(&self, request: &AddUserRequest) -> Result<AddUserResponse, GrafanaCliError> {
+        self.client.post("admin/users", request).await
+    }
+
+    /// Changes the name, email and login of the user, all fields are always replaced
+    pub async fn update(&self, user_id: u32, request: &UpdateUserRequest) -> Result<UserMessageResponse, GrafanaCliError> {
+        self.client.put(&format!("users/{}", user_id), request).await
+    }
+
+    pub async fn disable(&self, user_id: u32) -> Result<UserMessageResponse, GrafanaCliError> {
+        self.client.post(&format!("admin/users/{}/disable", user_id), &()).await
+    }
+
+    pub async fn enable(&self, user_id: u32) -> Result<UserMessageResponse, GrafanaCliError> {
+        self.client.post(&format!("admin/users/{}/enable", user_id), &()).await
+    }
+
+    /// Grants or revokes the server wide grafana admin flag
+    pub async fn set_grafana_admin(&self, user_id: u32, is_grafana_admin: bool) -> Result<UserMessageResponse, GrafanaCliError> {
+        self.client.put(&format!("admin/users/{}/permissions", user_id), &GrafanaAdminRequest { is_grafana_admin }).await
+    }
+
+    /// Changes the basic role of the user in the current organization (see --org-id)
+    pub async fn set_org_role(&self, user_id: u32, role: OrgRole) -> Result<UserMessageResponse, GrafanaCliError> {
+        self.client.patch(&format!("org/users/{}", user_id), &OrgRoleRequest { role }).await
+    }
+
+    pub async fn teams(&self, user_id: u32) -> Result<Vec<GetTeamResponse>, GrafanaCliError> {
+        self.client.get(&format!("users/{}/teams", user_id)).await
+    }
+
+    pub async fn orgs(&self, user_id: u32) -> Result<Vec<UserOrgResponse>, GrafanaCliError> {
+        self.client.get(&format!("users/{}/orgs", user_id)).await
+    }
+
+    /// Deletes the user from all organizations with the admin api
+    pub async fn delete(&self, user_id: u32) -> Result<UserMessageResponse, GrafanaCliError> {
+        self.client.del(&format!("admin/users/{}", user_id)).await
+    }
+}
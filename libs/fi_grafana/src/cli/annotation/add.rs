@@ -1,83 +1,36 @@
-use serde::Deserialize;
-use serde_with::serde_derive::Serialize;
-
+use crate::api::annotation::{AddAnnotationRequest, AddAnnotationResponse};
 use crate::api::grafana::GrafanaClient;
+use crate::api::search::{DASH_DB_TYPE, DASH_FOLDER_TYPE, SearchDashTypeRequest, TIME_SERIES_PANEL_TYPE};
 use crate::cli::annotation::options::AnnotationOptions;
-use crate::cli::dashboard::get::get_dashboard_by_uid;
-use crate::cli::dashboard::search::{DASH_DB_TYPE, DASH_FOLDER_TYPE, get_dash_type_uids, SearchDashTypeRequest, TIME_SERIES_PANEL_TYPE};
 use crate::cli::shell::date::{DATETIME_FORMAT, from_datetime_to_epoch_time_millis, parse_datetime_to_epoch_time_millis};
 use crate::cli::shell::input::prompt_option;
+use crate::cli::shell::output::{cell, OutputFormat, Tabular};
 use crate::error::GrafanaCliError;
 
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct AddAnnotationRequest {
-    /// When dashboard_uid and panel_id are not set, it will be an organizational annotation
-    pub dashboard_uid: Option<String>,
-    /// When dashboard_uid and panel_id are not set, it will be an organizational annotation
-    pub panel_id: Option<u32>,
-    /// Epoch time in millisecond resolution
-    pub time: Option<i64>,
-    /// Epoch time in millisecond resolution (when setting the time_end field, it will be a regional annotation)
-    pub time_end: Option<i64>,
-    /// Tags associated with this annotation
-    pub tags: Vec<String>,
-    /// Description of the annotation
-    pub text: String,
-}
+impl Tabular for AddAnnotationResponse {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["ID", "MESSAGE"]
+    }
 
-#[derive(Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct AddAnnotationResponse {
-    /// When the annotation was created the response contains its id
-    id: Option<u32>,
-    /// The status message whether the annotation was created or not
-    message: String,
+    fn row(&self, _wide: bool) -> Vec<String> {
+        vec![cell(&self.id), self.message.clone()]
+    }
 }
 
-pub async fn handle_add_annotation(grafana_client: &GrafanaClient, opt: &AnnotationOptions) {
+pub async fn handle_add_annotation(grafana_client: &GrafanaClient, opt: &AnnotationOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let comment = prompt_option("Enter a comment: ", &opt.comment).ok_or(GrafanaCliError::CanNotAddAnnotationWithoutComment)?;
+    let opt = &AnnotationOptions { comment: Some(comment), ..opt.clone() };
     if opt.all_panel_where_dashboard_name_is_like.is_some() || opt.within_folders_where_folder_name_is_like.is_some() {
-        match add_annotations_to_all_panel_within_the_specified_dash_type_scope(grafana_client, opt).await {
-            Ok(response) => {
-                for r in &response {
-                    if let Some(id) = r.id {
-                        println!("id: {}", id);
-                        println!("message: {}", r.message);
-                    }
-                }
-            }
-            Err(error) => {
-                eprintln!("{}", error);
-            }
-        }
-        return;
-    }
-    if opt.organizational {
-        return match add_organizational_annotation(grafana_client, opt).await {
-            Ok(response) => {
-                if let Some(id) = response.id {
-                    println!("{} [id: {}]", response.message, id);
-                } else {
-                    println!("{}", response.message);
-                }
-            }
-            Err(error) => {
-                eprintln!("{}", error);
-            }
-        };
-    }
-    match add_annotation_to_dashboard_panel(grafana_client, opt).await {
-        Ok(response) => {
-            if let Some(id) = response.id {
-                println!("{} [id: {}]", response.message, id);
-            } else {
-                println!("{}", response.message);
-            }
-        }
-        Err(error) => {
-            eprintln!("{}", error);
-        }
+        let response = add_annotations_to_all_panel_within_the_specified_dash_type_scope(grafana_client, opt).await?;
+        output.message(format!("Annotations ({}):", response.len()));
+        return output.print(&response);
     }
+    let response = if opt.organizational {
+        add_organizational_annotation(grafana_client, opt, output).await?
+    } else {
+        add_annotation_to_dashboard_panel(grafana_client, opt, output).await?
+    };
+    output.print_one(&response)
 }
 
 async fn add_annotations_to_all_panel_within_the_specified_dash_type_scope(grafana_client: &GrafanaClient, opt: &AnnotationOptions) -> Result<Vec<AddAnnotationResponse>, GrafanaCliError> {
@@ -91,25 +44,30 @@ async fn add_annotations_to_all_panel_within_the_specified_dash_type_scope(grafa
     } else {
         return Err(GrafanaCliError::CanNotParseTheEndDateTimeToEpochTimeMillis);
     };
+    let named_dashboard_uids = get_dashboard_uids_in_scope(grafana_client, opt).await?;
+    add_annotation_to_all_panels_with_type(grafana_client, TIME_SERIES_PANEL_TYPE, named_dashboard_uids, opt, time, time_end).await
+}
+
+/// Resolves the dashboards where the name is like the given value within the folders where the name is like the given value
+pub async fn get_dashboard_uids_in_scope(grafana_client: &GrafanaClient, opt: &AnnotationOptions) -> Result<Vec<String>, GrafanaCliError> {
     let folder_uids = if let Some(folder_name) = &opt.within_folders_where_folder_name_is_like {
         let request = SearchDashTypeRequest::type_query(DASH_FOLDER_TYPE.to_string(), folder_name.clone());
-        get_dash_type_uids(grafana_client, request).await?
+        grafana_client.search().dash_type_uids(&request).await?
     } else {
         vec![]
     };
     let dashboard_uids = if let Some(dashboard_name) = &opt.all_panel_where_dashboard_name_is_like {
         let request = SearchDashTypeRequest::type_query(DASH_DB_TYPE.to_string(), dashboard_name.clone());
-        get_dash_type_uids(grafana_client, request).await?
+        grafana_client.search().dash_type_uids(&request).await?
     } else {
         vec![]
     };
-    let named_dashboard_uids = get_dash_type_uids(grafana_client, SearchDashTypeRequest {
+    grafana_client.search().dash_type_uids(&SearchDashTypeRequest {
         r#type: Some(DASH_DB_TYPE.to_string()),
-        query: None,
         folder_uids: Some(folder_uids),
         dashboard_uids: Some(dashboard_uids),
-    }).await?;
-    Ok(add_annotation_to_all_panels_with_type(grafana_client, TIME_SERIES_PANEL_TYPE, named_dashboard_uids, opt, time, time_end).await?)
+        ..Default::default()
+    }).await
 }
 
 async fn add_annotation_to_all_panels_with_type(
@@ -122,20 +80,20 @@ async fn add_annotation_to_all_panels_with_type(
 ) -> Result<Vec<AddAnnotationResponse>, GrafanaCliError> {
     let mut responses = vec![];
     for dashboard_uid in &dashboard_uids {
-        let response = get_dashboard_by_uid(grafana_client, dashboard_uid).await?;
+        let response = grafana_client.dashboards().get(dashboard_uid).await?;
         if let Some(panels) = response.dashboard.panels {
             for panel in panels {
                 if panel.r#type.eq(panel_type) {
                     let request = AddAnnotationRequest {
                         dashboard_uid: Some(dashboard_uid.clone()),
-                        panel_id: Some(panel.id.clone()),
+                        panel_id: Some(panel.id),
                         time,
                         time_end,
                         tags: opt.tags.clone(),
-                        text: opt.comment.clone(),
+                        text: opt.comment.clone().unwrap_or_default(),
                     };
                     // todo: ask the user for confirmation to add an annotation to the panel
-                    responses.push(post_add_annotation(grafana_client, &request).await?);
+                    responses.push(grafana_client.annotations().create(&request).await?);
                 }
             }
         }
@@ -144,18 +102,18 @@ async fn add_annotation_to_all_panels_with_type(
 }
 
 
-async fn add_organizational_annotation(grafana_client: &GrafanaClient, opt: &AnnotationOptions) -> Result<AddAnnotationResponse, GrafanaCliError> {
+async fn add_organizational_annotation(grafana_client: &GrafanaClient, opt: &AnnotationOptions, output: OutputFormat) -> Result<AddAnnotationResponse, GrafanaCliError> {
     if opt.dashboard_uid.is_some() {
-        println!("Ignoring the 'dashboard_uid' because the 'organizational' flag is present which does not require a dashboard reference");
+        output.message("Ignoring the 'dashboard_uid' because the 'organizational' flag is present which does not require a dashboard reference");
     }
     if opt.panel_id.is_some() {
-        println!("Ignoring the 'panel_id' because the 'organizational' flag is present which does not require a panel reference");
+        output.message("Ignoring the 'panel_id' because the 'organizational' flag is present which does not require a panel reference");
     }
     if opt.start_datetime.is_some() {
-        println!("Ignoring the 'start_datetime' because the 'organizational' flag is present which does not require a specified datetime");
+        output.message("Ignoring the 'start_datetime' because the 'organizational' flag is present which does not require a specified datetime");
     }
     if opt.end_datetime.is_some() {
-        println!("Ignoring the 'end_datetime' because the 'organizational' flag is present which does not require a specified datetime");
+        output.message("Ignoring the 'end_datetime' because the 'organizational' flag is present which does not require a specified datetime");
     }
     let request = AddAnnotationRequest {
         dashboard_uid: None,
@@ -163,13 +121,13 @@ async fn add_organizational_annotation(grafana_client: &GrafanaClient, opt: &Ann
         time: None,
         time_end: None,
         tags: opt.tags.clone(),
-        text: opt.comment.clone(),
+        text: opt.comment.clone().unwrap_or_default(),
     };
-    post_add_annotation(grafana_client, &request).await
+    grafana_client.annotations().create(&request).await
 }
 
 
-async fn add_annotation_to_dashboard_panel(grafana_client: &GrafanaClient, opt: &AnnotationOptions) -> Result<AddAnnotationResponse, GrafanaCliError> {
+async fn add_annotation_to_dashboard_panel(grafana_client: &GrafanaClient, opt: &AnnotationOptions, output: OutputFormat) -> Result<AddAnnotationResponse, GrafanaCliError> {
     let dashboard_uid = prompt_option("Enter a dashboard_uid: ", &opt.dashboard_uid);
     let panel_id = prompt_option("Enter a panel_id: ", &opt.panel_id);
     let time = prompt_option(&format!("Enter a start_datetime [format: {}]: ", DATETIME_FORMAT), &opt.start_datetime);
@@ -182,7 +140,7 @@ async fn add_annotation_to_dashboard_panel(grafana_client: &GrafanaClient, opt:
         return Err(GrafanaCliError::CanNotParseTheEndDateTimeToEpochTimeMillis);
     }
     if let (Some(dashboard_uid), Some(panel_id)) = (&dashboard_uid, panel_id) {
-        println!("Adding an annotation to the dashboard's panel: [dashboard_uid: {}, panel_id: {}]", dashboard_uid, panel_id);
+        output.message(format!("Adding an annotation to the dashboard's panel: [dashboard_uid: {}, panel_id: {}]", dashboard_uid, panel_id));
     }
     let request = AddAnnotationRequest {
         dashboard_uid,
@@ -190,15 +148,7 @@ async fn add_annotation_to_dashboard_panel(grafana_client: &GrafanaClient, opt:
         time,
         time_end,
         tags: opt.tags.clone(),
-        text: opt.comment.clone(),
+        text: opt.comment.clone().unwrap_or_default(),
     };
-    post_add_annotation(grafana_client, &request).await
+    grafana_client.annotations().create(&request).await
 }
-
-async fn post_add_annotation(grafana_client: &GrafanaClient, request: &AddAnnotationRequest) -> Result<AddAnnotationResponse, GrafanaCliError> {
-    match grafana_client
-        .post("annotations", request).await {
-        Ok(response) => Ok(response.json::<AddAnnotationResponse>().await?),
-        Err(error) => Err(GrafanaCliError::Request(error))
-    }
-}
\ No newline at end of file
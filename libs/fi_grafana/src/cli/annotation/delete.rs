@@ -0,0 +1,68 @@
+use std::cmp::Reverse;
+
+use crate::api::annotation::{FindAnnotationsRequest, GetAnnotationResponse};
+use crate::api::grafana::GrafanaClient;
+use crate::cli::annotation::add::get_dashboard_uids_in_scope;
+use crate::cli::annotation::options::AnnotationOptions;
+use crate::cli::shell::input::confirm;
+use crate::cli::shell::output::OutputFormat;
+use crate::error::GrafanaCliError;
+
+pub async fn handle_del_annotation(grafana_client: &GrafanaClient, opt: &AnnotationOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    if let Some(id) = opt.id {
+        let response = grafana_client.annotations().delete(id).await?;
+        output.message(format!("{} [id: {}]", response.message, id));
+        return Ok(());
+    }
+    let annotations = find_annotations_in_scope(grafana_client, opt).await?;
+    output.message(format!("Annotations in scope ({}):", annotations.len()));
+    if annotations.is_empty() {
+        return Ok(());
+    }
+    output.print(&annotations)?;
+    if !confirm(&format!("Delete {} annotations?", annotations.len()), opt.yes) {
+        output.message("No delete confirmation. Skipping request..");
+        return Ok(());
+    }
+    let mut deleted = 0;
+    for annotation in &annotations {
+        match grafana_client.annotations().delete(annotation.id).await {
+            Ok(_) => deleted += 1,
+            Err(error) => eprintln!("{} [id: {}]", error, annotation.id),
+        }
+    }
+    output.message(format!("Deleted {} of {} annotations", deleted, annotations.len()));
+    if deleted < annotations.len() {
+        return Err(GrafanaCliError::SomeAnnotationsFailed(annotations.len() - deleted, annotations.len()));
+    }
+    Ok(())
+}
+
+/// Finds the annotations matching the tags and time range, within the dashboard or folder scope if given, the newest up to the limit
+async fn find_annotations_in_scope(grafana_client: &GrafanaClient, opt: &AnnotationOptions) -> Result<Vec<GetAnnotationResponse>, GrafanaCliError> {
+    let has_scope = opt.all_panel_where_dashboard_name_is_like.is_some() || opt.within_folders_where_folder_name_is_like.is_some();
+    let has_filter = has_scope
+        || !opt.tags.is_empty()
+        || opt.start_datetime.is_some()
+        || opt.end_datetime.is_some()
+        || opt.dashboard_uid.is_some()
+        || opt.user_id.is_some();
+    if !has_filter {
+        return Err(GrafanaCliError::CanNotDeleteAnnotationsWithoutFilter);
+    }
+    let request = FindAnnotationsRequest::new_by_option(opt)?;
+    if !has_scope {
+        return grafana_client.annotations().find_limited_or_all(&request).await;
+    }
+    let mut annotations = vec![];
+    for dashboard_uid in get_dashboard_uids_in_scope(grafana_client, opt).await? {
+        let request = FindAnnotationsRequest { dashboard_uid: Some(dashboard_uid), ..request.clone() };
+        annotations.append(&mut grafana_client.annotations().find_limited_or_all(&request).await?);
+    }
+    if let Some(limit) = opt.limit {
+        // each dashboard is limited on its own, the limit applies to the newest across all of them
+        annotations.sort_by_key(|annotation| Reverse(annotation.time));
+        annotations.truncate(limit as usize);
+    }
+    Ok(annotations)
+}
@@ -0,0 +1,61 @@
+use crate::api::annotation::{FindAnnotationsRequest, GetAnnotationResponse};
+use crate::api::grafana::GrafanaClient;
+use crate::cli::annotation::options::AnnotationOptions;
+use crate::cli::shell::date::{from_datetime_to_epoch_time_millis, from_epoch_time_millis_to_local_datetime};
+use crate::cli::shell::output::{OutputFormat, Tabular};
+use crate::error::GrafanaCliError;
+
+impl Tabular for GetAnnotationResponse {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["ID", "START", "END", "DASHBOARD_UID", "PANEL_ID", "TAGS", "TEXT"];
+        if wide {
+            headers.extend(["TYPE", "LOGIN", "CREATED"]);
+        }
+        headers
+    }
+
+    fn row(&self, wide: bool) -> Vec<String> {
+        let end = if self.time_end != self.time {
+            from_epoch_time_millis_to_local_datetime(self.time_end)
+        } else {
+            "-".to_string()
+        };
+        let mut row = vec![
+            self.id.to_string(),
+            from_epoch_time_millis_to_local_datetime(self.time),
+            end,
+            self.dashboard_uid.clone().unwrap_or("-".to_string()),
+            self.panel_id.to_string(),
+            self.tags.join(","),
+            self.text.clone(),
+        ];
+        if wide {
+            let r#type = if self.alert_id > 0 { "alert" } else { "annotation" };
+            row.extend([r#type.to_string(), self.login.clone(), from_epoch_time_millis_to_local_datetime(self.created)]);
+        }
+        row
+    }
+}
+
+impl FindAnnotationsRequest {
+    pub fn new_by_option(opt: &AnnotationOptions) -> Result<Self, GrafanaCliError> {
+        Ok(Self {
+            from: opt.start_datetime.as_deref().map(from_datetime_to_epoch_time_millis).transpose()?,
+            to: opt.end_datetime.as_deref().map(from_datetime_to_epoch_time_millis).transpose()?,
+            user_id: opt.user_id,
+            dashboard_uid: opt.dashboard_uid.clone(),
+            panel_id: opt.panel_id,
+            r#type: opt.annotation_type,
+            tags: opt.tags.clone(),
+            match_any: opt.match_any.then_some(true),
+            limit: opt.limit,
+        })
+    }
+}
+
+pub async fn handle_get_annotation(grafana_client: &GrafanaClient, opt: &AnnotationOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let request = FindAnnotationsRequest::new_by_option(opt)?;
+    let annotations = grafana_client.annotations().find_limited_or_all(&request).await?;
+    output.message(format!("Annotations ({}):", annotations.len()));
+    output.print(&annotations)
+}
@@ -1,2 +1,5 @@
 pub mod add;
+pub mod delete;
+pub mod get;
 pub mod options;
+pub mod set;
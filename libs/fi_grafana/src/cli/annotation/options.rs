@@ -1,26 +1,31 @@
 use clap::Args;
 
+use crate::api::annotation::AnnotationType;
+
 /// The available options for annotation requests
 #[derive(Debug, Args, Clone)]
 pub struct AnnotationOptions {
-    /// Use it in conjunction with the panel_id to add an annotation to a panel of a dashboard
+    /// Use it to set or delete the annotation with this id
+    #[arg(short, long)]
+    pub id: Option<u32>,
+    /// Use it in conjunction with the panel_id to add an annotation to a panel of a dashboard (or to get the annotations of a dashboard)
     #[arg(short, long)]
     pub dashboard_uid: Option<String>,
-    /// Use it in conjunction with the dashboard_uid to add an annotation to a panel of a dashboard
+    /// Use it in conjunction with the dashboard_uid to add an annotation to a panel of a dashboard (or to get the annotations of a panel)
     #[arg(short, long)]
     pub panel_id: Option<u32>,
-    /// Use it to specify the datetime where the annotation should be placed [format: %Y-%m-%d %H:%M]
+    /// Use it to specify the datetime where the annotation should be placed (or where the time range to get starts) in local time [format: %Y-%m-%d %H:%M]
     #[arg(short, long)]
     pub start_datetime: Option<String>,
-    /// Use it to specify a regional annotation with a datetime end [format: %Y-%m-%d %H:%M]
+    /// Use it to specify a regional annotation with a datetime end (or where the time range to get ends) in local time [format: %Y-%m-%d %H:%M]
     #[arg(short, long)]
     pub end_datetime: Option<String>,
-    /// Use it to add tags to the annotation being added
+    /// Use it to add tags to the annotation being added (or to get annotations with all of these tags)
     #[arg(short, long)]
     pub tags: Vec<String>,
     /// Use it to describe the annotation with a short comment
     #[arg(short, long)]
-    pub comment: String,
+    pub comment: Option<String>,
     /// Use it to add an organizational annotation that is not associated with a panel of a dashboard
     #[arg(short, long, default_value_t = false)]
     pub organizational: bool,
@@ -30,4 +35,19 @@ pub struct AnnotationOptions {
     /// Use it in conjunction with the (all_panel_where_dashboard_name_is_like) option to scope the dashboard search to folders where the folder name is like the given value
     #[arg(short, long)]
     pub within_folders_where_folder_name_is_like: Option<String>,
-}
\ No newline at end of file
+    /// Use it to get only the annotations created by the user with this id
+    #[arg(long)]
+    pub user_id: Option<u32>,
+    /// Use it to get only annotations of this type
+    #[arg(long = "type", value_enum)]
+    pub annotation_type: Option<AnnotationType>,
+    /// Use it to get annotations that have any instead of all of the given tags
+    #[arg(long, default_value_t = false)]
+    pub match_any: bool,
+    /// Use it to limit the number of annotations to get or delete, the newest first (otherwise all annotations in scope are fetched)
+    #[arg(short, long)]
+    pub limit: Option<u32>,
+    /// Use it to confirm the deletion of all annotations in scope upfront
+    #[arg(short, long, default_value_t = false)]
+    pub yes: bool,
+}
@@ -0,0 +1,27 @@
+use crate::api::annotation::PatchAnnotationRequest;
+use crate::api::grafana::GrafanaClient;
+use crate::cli::annotation::options::AnnotationOptions;
+use crate::cli::shell::date::from_datetime_to_epoch_time_millis;
+use crate::cli::shell::input::prompt_option;
+use crate::cli::shell::output::OutputFormat;
+use crate::error::GrafanaCliError;
+
+impl PatchAnnotationRequest {
+    /// Only the given fields are sent, the rest of the annotation stays as it is
+    pub fn new_by_option(opt: &AnnotationOptions) -> Result<Self, GrafanaCliError> {
+        Ok(Self {
+            text: opt.comment.clone(),
+            tags: (!opt.tags.is_empty()).then(|| opt.tags.clone()),
+            time: opt.start_datetime.as_deref().map(from_datetime_to_epoch_time_millis).transpose()?,
+            time_end: opt.end_datetime.as_deref().map(from_datetime_to_epoch_time_millis).transpose()?,
+        })
+    }
+}
+
+pub async fn handle_set_annotation(grafana_client: &GrafanaClient, opt: &AnnotationOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let id = prompt_option("Enter the annotation id: ", &opt.id).ok_or(GrafanaCliError::CanNotSetAnnotationWithoutId)?;
+    let request = PatchAnnotationRequest::new_by_option(opt)?;
+    let response = grafana_client.annotations().update(id, &request).await?;
+    output.message(format!("{} [id: {}]", response.message, id));
+    Ok(())
+}
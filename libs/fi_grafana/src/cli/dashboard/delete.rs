@@ -0,0 +1,43 @@
+use crate::api::grafana::GrafanaClient;
+use crate::api::search::{SearchDashTypeRequest, DASH_DB_TYPE};
+use crate::cli::dashboard::options::DashboardOptions;
+use crate::cli::shell::input::confirm;
+use crate::cli::shell::output::OutputFormat;
+use crate::error::GrafanaCliError;
+
+/// Deletes the dashboard with the uid or all dashboards matching the query, tags and folder after listing them for confirmation
+pub async fn handle_del_dashboard(grafana_client: &GrafanaClient, opt: &DashboardOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    if opt.uid.is_none() && opt.query.is_none() && opt.tags.is_empty() && opt.folder_uid.is_none() {
+        return Err(GrafanaCliError::CanNotDeleteDashboardsWithoutFilter);
+    }
+    let request = SearchDashTypeRequest {
+        r#type: Some(DASH_DB_TYPE.to_string()),
+        query: opt.query.clone(),
+        folder_uids: opt.folder_uid.clone().map(|folder_uid| vec![folder_uid]),
+        dashboard_uids: opt.uid.clone().map(|uid| vec![uid]),
+        tag: (!opt.tags.is_empty()).then(|| opt.tags.clone()),
+        ..Default::default()
+    };
+    let dashboards = grafana_client.search().dash_types_all(&request).await?;
+    if dashboards.is_empty() {
+        output.message("No dashboards found to delete");
+        return Ok(());
+    }
+    output.print(&dashboards)?;
+    if !confirm(&format!("Delete the [{}] dashboards listed above?", dashboards.len()), opt.yes) {
+        output.message("No delete confirmation. Skipping request..");
+        return Ok(());
+    }
+    let mut deleted = 0;
+    for dashboard in &dashboards {
+        match grafana_client.dashboards().delete(&dashboard.uid).await {
+            Ok(_) => deleted += 1,
+            Err(error) => eprintln!("Failed to delete the dashboard {} [{}]: {}", dashboard.title, dashboard.uid, error),
+        }
+    }
+    output.message(format!("Deleted {} of {} dashboards", deleted, dashboards.len()));
+    if deleted < dashboards.len() {
+        return Err(GrafanaCliError::SomeDashboardsFailed(dashboards.len() - deleted, dashboards.len()));
+    }
+    Ok(())
+}
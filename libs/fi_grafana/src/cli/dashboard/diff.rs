@@ -0,0 +1,247 @@
+use std::collections::BTreeSet;
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::api::grafana::GrafanaClient;
+use crate::cli::dashboard::options::DashboardDiffOptions;
+use crate::cli::shell::output::{OutputFormat, Tabular};
+use crate::error::GrafanaCliError;
+
+/// The dashboard fields which are compared separately or change with every version
+const IGNORED_FIELDS: [&str; 4] = ["id", "version", "panels", "templating"];
+/// The longest value rendered into the detail of a change
+const MAX_VALUE_LENGTH: usize = 60;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Change {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Added => write!(f, "added"),
+            Change::Removed => write!(f, "removed"),
+            Change::Changed => write!(f, "changed"),
+        }
+    }
+}
+
+/// A difference of a dashboard field, a panel or a template variable between two versions
+#[derive(Debug, Serialize)]
+pub struct DashboardChange {
+    /// dashboard, panel or variable
+    pub section: &'static str,
+    pub name: String,
+    pub change: Change,
+    pub detail: String,
+}
+
+impl Tabular for DashboardChange {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["SECTION", "NAME", "CHANGE", "DETAIL"]
+    }
+
+    fn row(&self, _wide: bool) -> Vec<String> {
+        vec![self.section.to_string(), self.name.clone(), self.change.to_string(), self.detail.clone()]
+    }
+}
+
+/// A panel or variable with the key it is matched by between the versions
+struct Item {
+    key: String,
+    name: String,
+    value: Value,
+}
+
+pub async fn handle_diff_dashboard(grafana_client: &GrafanaClient, opt: &DashboardDiffOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let from = grafana_client.dashboards().version(&opt.uid, opt.from_version).await?.data;
+    let to = grafana_client.dashboards().version(&opt.uid, opt.to_version).await?.data;
+    let changes = diff_dashboards(&from, &to);
+    output.print(&changes)?;
+    output.message(format!("{} changes from version {} to {} of the dashboard {}", changes.len(), opt.from_version, opt.to_version, opt.uid));
+    Ok(())
+}
+
+/// Compares the fields of the dashboards, the panels matched by id and the template variables matched by name
+pub fn diff_dashboards(from: &Value, to: &Value) -> Vec<DashboardChange> {
+    let mut changes = diff_fields(from, to);
+    changes.extend(diff_items("panel", panels(from), panels(to)));
+    changes.extend(diff_items("variable", variables(from), variables(to)));
+    changes
+}
+
+fn diff_fields(from: &Value, to: &Value) -> Vec<DashboardChange> {
+    let keys: BTreeSet<&String> = [from, to]
+        .iter()
+        .filter_map(|dashboard| dashboard.as_object())
+        .flat_map(|dashboard| dashboard.keys())
+        .filter(|key| !IGNORED_FIELDS.contains(&key.as_str()))
+        .collect();
+    keys.into_iter()
+        .filter_map(|key| {
+            let (change, detail) = match (from.get(key), to.get(key)) {
+                (None, Some(value)) => (Change::Added, render(value)),
+                (Some(value), None) => (Change::Removed, render(value)),
+                (Some(before), Some(after)) if before != after => (Change::Changed, format!("{} -> {}", render(before), render(after))),
+                _ => return None,
+            };
+            Some(DashboardChange { section: "dashboard", name: key.clone(), change, detail })
+        })
+        .collect()
+}
+
+fn diff_items(section: &'static str, from: Vec<Item>, to: Vec<Item>) -> Vec<DashboardChange> {
+    let mut changes = Vec::new();
+    for before in &from {
+        match to.iter().find(|after| after.key == before.key) {
+            None => changes.push(DashboardChange { section, name: before.name.clone(), change: Change::Removed, detail: String::new() }),
+            Some(after) if after.value != before.value => {
+                changes.push(DashboardChange { section, name: after.name.clone(), change: Change::Changed, detail: changed_keys(&before.value, &after.value) });
+            }
+            Some(_) => {}
+        }
+    }
+    for after in to.iter().filter(|after| !from.iter().any(|before| before.key == after.key)) {
+        changes.push(DashboardChange { section, name: after.name.clone(), change: Change::Added, detail: String::new() });
+    }
+    changes
+}
+
+/// The names of the fields which differ between both objects
+fn changed_keys(before: &Value, after: &Value) -> String {
+    let keys: BTreeSet<&String> = [before, after]
+        .iter()
+        .filter_map(|value| value.as_object())
+        .flat_map(|value| value.keys())
+        .filter(|key| before.get(key.as_str()) != after.get(key.as_str()))
+        .collect();
+    keys.into_iter().cloned().collect::<Vec<String>>().join(",")
+}
+
+/// The panels including the ones within collapsed rows, the rows without their nested panels
+fn panels(dashboard: &Value) -> Vec<Item> {
+    let mut items = Vec::new();
+    for panel in dashboard["panels"].as_array().into_iter().flatten() {
+        let mut row = panel.clone();
+        let nested = row.as_object_mut().and_then(|row| row.remove("panels"));
+        items.push(panel_item(row));
+        for nested in nested.as_ref().and_then(Value::as_array).into_iter().flatten() {
+            items.push(panel_item(nested.clone()));
+        }
+    }
+    items
+}
+
+fn panel_item(panel: Value) -> Item {
+    let title = panel["title"].as_str().unwrap_or_default().to_string();
+    let (key, name) = match panel["id"].as_u64() {
+        Some(id) => (id.to_string(), format!("#{} {}", id, title)),
+        None => (title.clone(), title),
+    };
+    Item { key, name, value: panel }
+}
+
+fn variables(dashboard: &Value) -> Vec<Item> {
+    dashboard["templating"]["list"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|variable| {
+            let name = variable["name"].as_str().unwrap_or_default().to_string();
+            Item { key: name.clone(), name, value: variable.clone() }
+        })
+        .collect()
+}
+
+/// The compact json of the value shortened to a readable length
+fn render(value: &Value) -> String {
+    let json = value.to_string();
+    match json.char_indices().nth(MAX_VALUE_LENGTH) {
+        Some((index, _)) => format!("{}..", &json[..index]),
+        None => json,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn change<'a>(changes: &'a [DashboardChange], section: &str, name: &str) -> &'a DashboardChange {
+        changes.iter().find(|change| change.section == section && change.name == name).unwrap()
+    }
+
+    #[test]
+    fn equal_dashboards_have_no_changes() {
+        let dashboard = json!({"title": "a", "panels": [{"id": 1, "title": "cpu"}], "templating": {"list": [{"name": "host"}]}});
+        assert!(diff_dashboards(&dashboard, &dashboard).is_empty());
+    }
+
+    #[test]
+    fn fields_are_added_removed_and_changed() {
+        let from = json!({"title": "a", "refresh": "5s", "tags": ["x"]});
+        let to = json!({"title": "b", "timezone": "utc", "tags": ["x"]});
+        let changes = diff_dashboards(&from, &to);
+        assert_eq!(changes.len(), 3);
+        assert_eq!(change(&changes, "dashboard", "title").change, Change::Changed);
+        assert_eq!(change(&changes, "dashboard", "title").detail, r#""a" -> "b""#);
+        assert_eq!(change(&changes, "dashboard", "timezone").change, Change::Added);
+        assert_eq!(change(&changes, "dashboard", "refresh").change, Change::Removed);
+    }
+
+    #[test]
+    fn id_and_version_are_ignored() {
+        let from = json!({"id": 1, "version": 3, "title": "a"});
+        let to = json!({"id": 2, "version": 4, "title": "a"});
+        assert!(diff_dashboards(&from, &to).is_empty());
+    }
+
+    #[test]
+    fn panels_are_matched_by_id() {
+        let from = json!({"panels": [{"id": 1, "title": "cpu", "type": "graph"}, {"id": 2, "title": "mem"}]});
+        let to = json!({"panels": [{"id": 1, "title": "cpu usage", "type": "timeseries"}, {"id": 3, "title": "disk"}]});
+        let changes = diff_dashboards(&from, &to);
+        assert_eq!(changes.len(), 3);
+        let changed = change(&changes, "panel", "#1 cpu usage");
+        assert_eq!(changed.change, Change::Changed);
+        assert_eq!(changed.detail, "title,type");
+        assert_eq!(change(&changes, "panel", "#2 mem").change, Change::Removed);
+        assert_eq!(change(&changes, "panel", "#3 disk").change, Change::Added);
+    }
+
+    #[test]
+    fn panels_within_collapsed_rows_are_compared_on_their_own() {
+        let from = json!({"panels": [{"id": 1, "type": "row", "title": "row", "panels": [{"id": 2, "title": "cpu"}]}]});
+        let to = json!({"panels": [{"id": 1, "type": "row", "title": "row", "panels": [{"id": 2, "title": "cpu", "transparent": true}]}]});
+        let changes = diff_dashboards(&from, &to);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(change(&changes, "panel", "#2 cpu").detail, "transparent");
+    }
+
+    #[test]
+    fn variables_are_matched_by_name() {
+        let from = json!({"templating": {"list": [{"name": "host", "query": "a"}, {"name": "env"}]}});
+        let to = json!({"templating": {"list": [{"name": "host", "query": "b"}, {"name": "region"}]}});
+        let changes = diff_dashboards(&from, &to);
+        assert_eq!(changes.len(), 3);
+        assert_eq!(change(&changes, "variable", "host").change, Change::Changed);
+        assert_eq!(change(&changes, "variable", "env").change, Change::Removed);
+        assert_eq!(change(&changes, "variable", "region").change, Change::Added);
+    }
+
+    #[test]
+    fn long_values_are_shortened() {
+        let from = json!({"description": "a".repeat(100)});
+        let to = json!({});
+        let changes = diff_dashboards(&from, &to);
+        assert_eq!(changes[0].detail.chars().count(), MAX_VALUE_LENGTH + 2);
+        assert!(changes[0].detail.ends_with(".."));
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::folder::GetFolderResponse;
+use crate::api::grafana::GrafanaClient;
+use crate::api::search::{SearchDashTypeRequest, DASH_DB_TYPE};
+use crate::cli::dashboard::options::DashboardOptions;
+use crate::cli::shell::output::{OutputFormat, Tabular};
+use crate::error::GrafanaCliError;
+
+/// The directory of the dashboards without a folder
+pub const GENERAL_FOLDER_DIR: &str = "General";
+/// The file within a folder directory which keeps the folder metadata
+pub const FOLDER_META_FILE: &str = ".folder.json";
+
+/// The folder metadata stored next to the exported dashboards
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedFolder {
+    pub uid: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_uid: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExportedDashboard {
+    pub uid: String,
+    pub title: String,
+    pub folder: String,
+    pub file: PathBuf,
+}
+
+impl Tabular for ExportedDashboard {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["UID", "TITLE", "FOLDER", "FILE"]
+    }
+
+    fn row(&self, _wide: bool) -> Vec<String> {
+        vec![self.uid.clone(), self.title.clone(), self.folder.clone(), self.file.display().to_string()]
+    }
+}
+
+/// Makes a title usable as a file or directory name which stays the same between exports
+pub fn file_name(title: &str) -> String {
+    let name: String = title
+        .trim()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | ' ') { c } else { '-' })
+        .collect();
+    let name = name.trim_matches('.').trim();
+    if name.is_empty() { String::from("untitled") } else { name.to_string() }
+}
+
+/// Writes the complete dashboard models in scope to dir/folder-title-uid/../dashboard-slug.json, nested like their folders
+pub async fn handle_export_dashboards(grafana_client: &GrafanaClient, opt: &DashboardOptions, dir: &Path, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let uids = dashboard_uids_in_scope(grafana_client, opt).await?;
+    let mut folder_dirs: HashMap<String, PathBuf> = HashMap::new();
+    let mut exported = Vec::new();
+    for uid in &uids {
+        match export_dashboard(grafana_client, uid, dir, &mut folder_dirs).await {
+            Ok(dashboard) => exported.push(dashboard),
+            Err(error) => eprintln!("Failed to export the dashboard {}: {}", uid, error),
+        }
+    }
+    output.print(&exported)?;
+    output.message(format!("Exported {} of {} dashboards to {}", exported.len(), uids.len(), dir.display()));
+    if exported.len() < uids.len() {
+        return Err(GrafanaCliError::SomeDashboardsFailed(uids.len() - exported.len(), uids.len()));
+    }
+    Ok(())
+}
+
+async fn dashboard_uids_in_scope(grafana_client: &GrafanaClient, opt: &DashboardOptions) -> Result<Vec<String>, GrafanaCliError> {
+    if let Some(uid) = &opt.uid {
+        return Ok(vec![uid.clone()]);
+    }
+    let request = SearchDashTypeRequest {
+        r#type: Some(DASH_DB_TYPE.to_string()),
+        folder_uids: opt.folder_uid.clone().map(|folder_uid| vec![folder_uid]),
+        tag: (!opt.tags.is_empty()).then(|| opt.tags.clone()),
+        ..Default::default()
+    };
+    let mut uids = grafana_client.search().dash_type_uids(&request).await?;
+    uids.sort();
+    Ok(uids)
+}
+
+async fn export_dashboard(grafana_client: &GrafanaClient, uid: &str, dir: &Path, folder_dirs: &mut HashMap<String, PathBuf>) -> Result<ExportedDashboard, GrafanaCliError> {
+    let response = grafana_client.dashboards().get_json(uid).await?;
+    let meta = &response["meta"];
+    let dashboard = &response["dashboard"];
+    let title = dashboard["title"].as_str().unwrap_or(uid).to_string();
+    let folder_uid = meta["folderUid"].as_str().unwrap_or_default();
+    let folder_dir = export_folder(grafana_client, folder_uid, dir, folder_dirs).await?;
+    let slug = meta["slug"].as_str().filter(|slug| !slug.is_empty()).map(file_name).unwrap_or_else(|| file_name(&title));
+    let file = dir.join(&folder_dir).join(format!("{}.json", slug));
+    write_json(&file, dashboard)?;
+    Ok(ExportedDashboard { uid: uid.to_string(), title, folder: folder_dir.display().to_string(), file })
+}
+
+/// The name of a folder directory, the uid keeps folders with the same title apart the same way in every export
+fn folder_dir_name(title: &str, uid: &str) -> String {
+    format!("{}-{}", file_name(title), file_name(uid))
+}
+
+/// Creates the directories of the folder and its parent folders with their metadata and returns the one of the folder
+async fn export_folder(grafana_client: &GrafanaClient, folder_uid: &str, dir: &Path, folder_dirs: &mut HashMap<String, PathBuf>) -> Result<PathBuf, GrafanaCliError> {
+    if folder_uid.is_empty() {
+        fs::create_dir_all(dir.join(GENERAL_FOLDER_DIR))?;
+        return Ok(PathBuf::from(GENERAL_FOLDER_DIR));
+    }
+    // the folders from this one up to the first one with a known directory or to the root level
+    let mut chain: Vec<GetFolderResponse> = Vec::new();
+    let mut folder_dir = PathBuf::new();
+    let mut next = Some(folder_uid.to_string());
+    while let Some(uid) = next.take() {
+        if let Some(known) = folder_dirs.get(&uid) {
+            folder_dir = known.clone();
+            break;
+        }
+        if chain.iter().any(|folder| folder.uid == uid) {
+            break;
+        }
+        let folder = grafana_client.folders().get(&uid).await?;
+        next = folder.parent_uid.clone().filter(|parent_uid| !parent_uid.is_empty());
+        chain.push(folder);
+    }
+    for folder in chain.into_iter().rev() {
+        folder_dir = folder_dir.join(folder_dir_name(&folder.title, &folder.uid));
+        let exported = ExportedFolder { uid: folder.uid.clone(), title: folder.title, parent_uid: folder.parent_uid };
+        write_json(&dir.join(&folder_dir).join(FOLDER_META_FILE), &exported)?;
+        folder_dirs.insert(folder.uid, folder_dir.clone());
+    }
+    Ok(folder_dir)
+}
+
+fn write_json<T: Serialize + ?Sized>(file: &Path, value: &T) -> Result<(), GrafanaCliError> {
+    if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut json = serde_json::to_string_pretty(value)?;
+    json.push('\n');
+    fs::write(file, json)?;
+    Ok(())
+}
+
@@ -1,138 +1,91 @@
-use serde::Deserialize;
-
+use crate::api::dashboard::{DashboardVersionResponse, GetDashboardResponse};
 use crate::api::grafana::GrafanaClient;
+use crate::api::search::DashTypeResponse;
+use crate::cli::dashboard::export::handle_export_dashboards;
 use crate::cli::dashboard::options::DashboardOptions;
+use crate::cli::shell::date::from_rfc3339_to_local_datetime;
+use crate::cli::shell::output::{cell, OutputFormat, Tabular};
 use crate::error::GrafanaCliError;
 
-#[derive(Debug, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct GetDashboardResponse {
-    pub dashboard: GetDashboard,
-    pub meta: GetDashboardMeta,
-}
-
-/// Some conditional fields of the resource are omitted (there are more to work with)
-#[derive(Debug, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct GetDashboard {
-    /// The incremental id of the grafana instance
-    pub id: u32,
-    /// The unique identifier across instances
-    pub uid: String,
-    /// The unique dashboard name within a folder
-    pub title: String,
-    /// The associated search tags
-    pub tags: Vec<String>,
-    /// The desired dashboard timezone
-    pub timezone: String,
-    /// The defined schema version
-    pub schema_version: u16,
-    /// The dashboard version
-    pub version: u8,
-    /// The available panels of the dashboard
-    pub panels: Option<Vec<GetPanel>>,
-}
-
-/// Some conditional fields of the resource are omitted (there are more to work with)
-#[derive(Debug, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct GetPanel {
-    pub id: u32,
-    pub title: String,
-    pub r#type: String,
-    pub datasource: GetPanelDataSource,
-    pub grid_pos: GetPanelGridPosition,
-}
-
-#[derive(Debug, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct GetPanelDataSource {
-    pub r#type: String,
-    pub uid: String,
-}
-
-#[derive(Debug, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct GetPanelGridPosition {
-    pub h: u16,
-    pub w: u16,
-    pub x: u16,
-    pub y: u16,
-}
+impl Tabular for GetDashboardResponse {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["ID", "UID", "TITLE", "VERSION", "FOLDER", "TAGS"];
+        if wide {
+            headers.extend(["TIMEZONE", "SCHEMA_VERSION", "FOLDER_UID", "URL"]);
+        }
+        headers
+    }
 
-#[derive(Debug, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct GetDashboardMeta {
-    pub r#type: String,
-    pub can_save: bool,
-    pub can_edit: bool,
-    pub can_admin: bool,
-    pub can_star: bool,
-    pub can_delete: bool,
-    pub slug: String,
-    pub url: String,
-    pub expires: String,
-    pub created: String,
-    pub updated: String,
-    pub updated_by: String,
-    pub created_by: String,
-    pub version: u16,
-    pub has_acl: bool,
-    pub is_folder: bool,
-    pub folder_id: u32,
-    pub folder_uid: String,
-    pub folder_title: String,
-    pub folder_url: String,
-    pub provisioned: bool,
-    pub provisioned_external_id: String,
-    pub annotations_permissions: AnnotationsPermissions,
+    fn row(&self, wide: bool) -> Vec<String> {
+        let mut row = vec![
+            self.dashboard.id.to_string(),
+            self.dashboard.uid.clone(),
+            self.dashboard.title.clone(),
+            self.dashboard.version.to_string(),
+            self.meta.folder_title.clone(),
+            self.dashboard.tags.join(","),
+        ];
+        if wide {
+            row.extend([
+                self.dashboard.timezone.clone(),
+                self.dashboard.schema_version.to_string(),
+                self.meta.folder_uid.clone(),
+                self.meta.url.clone(),
+            ]);
+        }
+        row
+    }
 }
 
-#[derive(Debug, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct AnnotationsPermissions {
-    pub dashboard: DashboardAnnotationPermissions,
-    pub organization: OrganizationAnnotationPermissions,
-}
+impl Tabular for DashTypeResponse {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["UID", "TITLE", "FOLDER", "TAGS"];
+        if wide {
+            headers.extend(["TYPE", "FOLDER_UID", "URL"]);
+        }
+        headers
+    }
 
-#[derive(Debug, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct DashboardAnnotationPermissions {
-    pub can_add: bool,
-    pub can_edit: bool,
-    pub can_delete: bool,
+    fn row(&self, wide: bool) -> Vec<String> {
+        let mut row = vec![self.uid.clone(), self.title.clone(), cell(&self.folder_title), self.tags.join(",")];
+        if wide {
+            row.extend([self.r#type.clone(), cell(&self.folder_uid), self.url.clone()]);
+        }
+        row
+    }
 }
 
-#[derive(Debug, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct OrganizationAnnotationPermissions {
-    pub can_add: bool,
-    pub can_edit: bool,
-    pub can_delete: bool,
-}
+impl Tabular for DashboardVersionResponse {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["VERSION", "CREATED", "CREATED_BY", "MESSAGE"];
+        if wide {
+            headers.extend(["PARENT_VERSION", "RESTORED_FROM"]);
+        }
+        headers
+    }
 
-pub async fn handle_get_dashboard(grafana_client: &GrafanaClient, opt: &DashboardOptions) {
-    if let Some(uid) = &opt.uid {
-        match get_dashboard_by_uid(grafana_client, uid).await {
-            Ok(response) => {
-                println!("Dashboard:");
-                println!("id: {} | uid: {} | name: {}", response.dashboard.id, response.dashboard.uid, response.dashboard.title);
-                println!("timezone: {} | version: {} | schema_version: {}", response.dashboard.timezone, response.dashboard.version, response.dashboard.schema_version);
-                println!("tags: [{}]", response.dashboard.tags.join(", "));
-                println!("Folder:");
-                println!("id: {} | uid: {} | title: {}", response.meta.folder_id, response.meta.folder_uid, response.meta.folder_title);
-                println!("url: {}", response.meta.url);
-            }
-            Err(error) => {
-                eprintln!("{}", error);
-            }
+    fn row(&self, wide: bool) -> Vec<String> {
+        let mut row = vec![self.version.to_string(), from_rfc3339_to_local_datetime(&self.created), self.created_by.clone(), self.message.clone()];
+        if wide {
+            let restored_from = if self.restored_from > 0 { self.restored_from.to_string() } else { String::new() };
+            row.extend([self.parent_version.to_string(), restored_from]);
         }
+        row
     }
 }
 
-pub async fn get_dashboard_by_uid(grafana_client: &GrafanaClient, uid: &String) -> Result<GetDashboardResponse, GrafanaCliError> {
-    match grafana_client.get(&format!("dashboards/uid/{}", uid)).await {
-        Ok(response) => Ok(response.json::<GetDashboardResponse>().await?),
-        Err(error) => Err(GrafanaCliError::Request(error))
+pub async fn handle_get_dashboard(grafana_client: &GrafanaClient, opt: &DashboardOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    if let Some(dir) = &opt.export {
+        return handle_export_dashboards(grafana_client, opt, dir, output).await;
+    }
+    if let (true, Some(uid)) = (opt.versions, &opt.uid) {
+        let versions = grafana_client.dashboards().versions(uid).await?;
+        output.print(&versions)?;
+        return Ok(());
     }
-}
\ No newline at end of file
+    if let Some(uid) = &opt.uid {
+        let response = grafana_client.dashboards().get(uid).await?;
+        output.print_one(&response)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::api::dashboard::SaveDashboardJsonRequest;
+use crate::api::folder::AddFolderRequest;
+use crate::api::grafana::GrafanaClient;
+use crate::api::search::{SearchDashTypeRequest, DASH_FOLDER_TYPE};
+use crate::cli::dashboard::export::{ExportedFolder, FOLDER_META_FILE, GENERAL_FOLDER_DIR};
+use crate::cli::dashboard::options::DashboardOptions;
+use crate::cli::shell::output::{OutputFormat, Tabular};
+use crate::error::GrafanaCliError;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportResult {
+    Created,
+    Updated,
+    Skipped,
+    Failed,
+}
+
+impl fmt::Display for ImportResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportResult::Created => write!(f, "created"),
+            ImportResult::Updated => write!(f, "updated"),
+            ImportResult::Skipped => write!(f, "skipped"),
+            ImportResult::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+/// The outcome of adding the dashboard of one file
+#[derive(Debug, Serialize)]
+pub struct ImportedDashboard {
+    pub file: PathBuf,
+    pub title: String,
+    pub uid: Option<String>,
+    pub folder: String,
+    pub result: ImportResult,
+    pub detail: String,
+}
+
+impl Tabular for ImportedDashboard {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["FILE", "TITLE", "UID", "RESULT"];
+        if wide {
+            headers.extend(["FOLDER", "DETAIL"]);
+        }
+        headers
+    }
+
+    fn row(&self, wide: bool) -> Vec<String> {
+        let mut row = vec![self.file.display().to_string(), self.title.clone(), self.uid.clone().unwrap_or_default(), self.result.to_string()];
+        if wide {
+            row.extend([self.folder.clone(), self.detail.clone()]);
+        }
+        row
+    }
+}
+
+/// Adds the dashboards of a json file or of the json files within a directory, the folder directories are mapped onto the
+/// target folders by their title and missing folders are created
+pub async fn handle_import_dashboards(grafana_client: &GrafanaClient, opt: &DashboardOptions, path: &Path, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let (root, files) = if path.is_dir() {
+        (path.to_path_buf(), dashboard_files(path)?)
+    } else {
+        (path.parent().map(Path::to_path_buf).unwrap_or_default(), vec![path.to_path_buf()])
+    };
+    let mut folders = FolderMapping::new(grafana_client, opt.folder_uid.clone()).await?;
+    let mut results = Vec::new();
+    for file in files {
+        let folder_titles = source_folder_titles(&file, &root)?;
+        let imported = import_dashboard(grafana_client, opt, &file, &folder_titles, &mut folders).await;
+        if imported.result == ImportResult::Failed {
+            eprintln!("Failed to add the dashboard of {}: {}", imported.file.display(), imported.detail);
+        }
+        results.push(imported);
+    }
+    output.print(&results)?;
+    let count = |result: ImportResult| results.iter().filter(|imported| imported.result == result).count();
+    output.message(format!(
+        "Created {}, updated {}, skipped {} and failed {} of {} dashboards",
+        count(ImportResult::Created),
+        count(ImportResult::Updated),
+        count(ImportResult::Skipped),
+        count(ImportResult::Failed),
+        results.len()
+    ));
+    if count(ImportResult::Failed) > 0 {
+        return Err(GrafanaCliError::SomeDashboardsFailed(count(ImportResult::Failed), results.len()));
+    }
+    Ok(())
+}
+
+/// The json files of the directory and its subdirectories in a stable order, without the folder metadata
+fn dashboard_files(dir: &Path) -> Result<Vec<PathBuf>, GrafanaCliError> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(dashboard_files(&path)?);
+        } else if path.extension().is_some_and(|extension| extension == "json") && path.file_name().is_some_and(|name| name != FOLDER_META_FILE) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// The titles of the folders the dashboard was exported from, from the root level down and empty for the general folder:
+/// the directories with folder metadata and the other ones below the imported directory stand for a folder each
+fn source_folder_titles(file: &Path, root: &Path) -> Result<Vec<String>, GrafanaCliError> {
+    let mut titles = Vec::new();
+    let mut dir = file.parent();
+    while let Some(current) = dir {
+        let meta_file = current.join(FOLDER_META_FILE);
+        if meta_file.is_file() {
+            let folder: ExportedFolder = serde_json::from_str(&fs::read_to_string(&meta_file)?)
+                .map_err(|error| GrafanaCliError::InvalidDashboardFile(meta_file.display().to_string(), error.to_string()))?;
+            titles.push(folder.title);
+        } else if current != root && current.starts_with(root) {
+            let name = current.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            if name != GENERAL_FOLDER_DIR {
+                titles.push(name);
+            }
+        } else {
+            break;
+        }
+        dir = current.parent();
+    }
+    titles.reverse();
+    Ok(titles)
+}
+
+async fn import_dashboard(grafana_client: &GrafanaClient, opt: &DashboardOptions, file: &Path, folder_titles: &[String], folders: &mut FolderMapping<'_>) -> ImportedDashboard {
+    let mut imported = ImportedDashboard {
+        file: file.to_path_buf(),
+        title: String::new(),
+        uid: None,
+        folder: if folder_titles.is_empty() { GENERAL_FOLDER_DIR.to_string() } else { folder_titles.join("/") },
+        result: ImportResult::Failed,
+        detail: String::new(),
+    };
+    let dashboard = match read_dashboard(file, opt) {
+        Ok(dashboard) => dashboard,
+        Err(error) => {
+            imported.detail = error.to_string();
+            return imported;
+        }
+    };
+    imported.title = dashboard["title"].as_str().unwrap_or_default().to_string();
+    imported.uid = dashboard["uid"].as_str().map(str::to_string);
+    let folder_uid = match folders.uid(folder_titles).await {
+        Ok(folder_uid) => folder_uid,
+        Err(error) => {
+            imported.result = skipped_on_dry_run(&error);
+            imported.detail = error.to_string();
+            return imported;
+        }
+    };
+    let existed = match &imported.uid {
+        Some(uid) => match grafana_client.dashboards().get_json(uid).await {
+            Ok(_) => Some(true),
+            Err(GrafanaCliError::NotFound(_)) => Some(false),
+            Err(error) => {
+                imported.detail = error.to_string();
+                return imported;
+            }
+        },
+        None => None,
+    };
+    if existed == Some(true) && !opt.overwrite {
+        imported.result = ImportResult::Skipped;
+        imported.detail = String::from("the dashboard exists, use --overwrite to replace it");
+        return imported;
+    }
+    let request = SaveDashboardJsonRequest {
+        dashboard,
+        folder_uid,
+        message: opt.message.clone().unwrap_or(format!("added from {}", file.display())),
+        overwrite: opt.overwrite,
+    };
+    match grafana_client.dashboards().save_json(&request).await {
+        Ok(response) => {
+            imported.uid = Some(response.uid);
+            imported.result = if existed.unwrap_or(response.version > 1) { ImportResult::Updated } else { ImportResult::Created };
+            imported.detail = format!("version {}", response.version);
+        }
+        // a dashboard with the same title in the folder or a newer version exists
+        Err(GrafanaCliError::PreconditionFailed(message)) if !opt.overwrite => {
+            imported.result = ImportResult::Skipped;
+            imported.detail = message;
+        }
+        Err(error) => {
+            imported.result = skipped_on_dry_run(&error);
+            imported.detail = error.to_string();
+        }
+    }
+    imported
+}
+
+/// A dry run records the request but can not tell the outcome of it
+fn skipped_on_dry_run(error: &GrafanaCliError) -> ImportResult {
+    match error {
+        GrafanaCliError::DryRunResponseRequired(_) => ImportResult::Skipped,
+        _ => ImportResult::Failed,
+    }
+}
+
+/// Reads the dashboard model of the file, a saved dashboard response with its meta data is unwrapped
+fn read_dashboard(file: &Path, opt: &DashboardOptions) -> Result<Value, GrafanaCliError> {
+    let invalid = |message: String| GrafanaCliError::InvalidDashboardFile(file.display().to_string(), message);
+    let mut value: Value = serde_json::from_str(&fs::read_to_string(file)?).map_err(|error| invalid(error.to_string()))?;
+    if value.get("dashboard").is_some_and(Value::is_object) {
+        value = value["dashboard"].take();
+    }
+    let Some(dashboard) = value.as_object_mut() else {
+        return Err(invalid(String::from("the content is not a json object")));
+    };
+    if !dashboard.get("title").is_some_and(Value::is_string) {
+        return Err(invalid(String::from("the dashboard has no title")));
+    }
+    if !opt.keep_id {
+        dashboard.remove("id");
+    }
+    if opt.strip_uid {
+        dashboard.remove("uid");
+    }
+    Ok(value)
+}
+
+/// Resolves the source folders onto the uids of the target folders with the same title below the same parent, creating the
+/// missing ones parents first
+struct FolderMapping<'a> {
+    grafana_client: &'a GrafanaClient,
+    /// The folder all dashboards go into regardless of their source folder
+    target_uid: Option<String>,
+    /// The target folders by the uid of their parent folder (none on the root level) and their title
+    uids: HashMap<(Option<String>, String), String>,
+}
+
+impl<'a> FolderMapping<'a> {
+    async fn new(grafana_client: &'a GrafanaClient, target_uid: Option<String>) -> Result<Self, GrafanaCliError> {
+        let mut uids = HashMap::new();
+        if target_uid.is_none() {
+            let request = SearchDashTypeRequest { r#type: Some(DASH_FOLDER_TYPE.to_string()), ..Default::default() };
+            for folder in grafana_client.search().dash_types_all(&request).await? {
+                let parent_uid = folder.folder_uid.filter(|parent_uid| !parent_uid.is_empty());
+                uids.entry((parent_uid, folder.title)).or_insert(folder.uid);
+            }
+        }
+        Ok(Self { grafana_client, target_uid, uids })
+    }
+
+    /// The uid of the target folder for the source folder titles from the root level down, none for the general folder
+    async fn uid(&mut self, titles: &[String]) -> Result<Option<String>, GrafanaCliError> {
+        if self.target_uid.is_some() {
+            return Ok(self.target_uid.clone());
+        }
+        let mut parent_uid = None;
+        for title in titles {
+            let key = (parent_uid.clone(), title.clone());
+            let uid = match self.uids.get(&key) {
+                Some(uid) => uid.clone(),
+                None => {
+                    let request = AddFolderRequest { uid: None, title: title.clone(), parent_uid: parent_uid.clone() };
+                    let folder = self.grafana_client.folders().create(&request).await?;
+                    eprintln!("Created the folder {} with uid {}", folder.title, folder.uid);
+                    self.uids.insert(key, folder.uid.clone());
+                    folder.uid
+                }
+            };
+            parent_uid = Some(uid);
+        }
+        Ok(parent_uid)
+    }
+}
@@ -1,4 +1,8 @@
 pub mod add;
+pub mod delete;
+pub mod diff;
+pub mod export;
+pub mod import;
 pub mod options;
 pub mod get;
-pub mod search;
\ No newline at end of file
+pub mod set;
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use clap::Args;
 
 /// The available option to post dashboards to grafana
@@ -12,7 +14,7 @@ pub struct DashboardOptions {
     /// Use it to set the name for the dashboard itself
     #[arg(short, long)]
     pub name: Option<String>,
-    /// Add search tags to the dashboard by specifying the new state
+    /// Add search tags to the dashboard by specifying the new state (or filter the dashboards by them)
     #[arg(short, long)]
     pub tags: Vec<String>,
     /// Specify the timezone of the dashboard [default_timezone: 'browser']
@@ -36,4 +38,42 @@ pub struct DashboardOptions {
     /// Use it do create a new folder for the new dashboard
     #[arg(short, long)]
     pub create_folder_name: Option<String>,
+    /// Use it to export the dashboards in scope (the uid, folder uid, tags or all) as json files into the directory
+    #[arg(long)]
+    pub export: Option<PathBuf>,
+    /// Use it to add the dashboards of a json file or of all json files within a directory (as written by the export)
+    #[arg(long)]
+    pub from_file: Option<PathBuf>,
+    /// Keep the instance id of the dashboards added from files (it is stripped by default)
+    #[arg(long)]
+    pub keep_id: bool,
+    /// Strip the uid of the dashboards added from files so grafana generates a new one
+    #[arg(long)]
+    pub strip_uid: bool,
+    /// Use it to get the saved versions of the dashboard with their author, date and message
+    #[arg(long, default_value_t = false, requires = "uid")]
+    pub versions: bool,
+    /// Use it to restore this previous version of the dashboard as a new version (with the message if given)
+    #[arg(long, conflicts_with = "move_to")]
+    pub restore: Option<u32>,
+    /// Use it to move the dashboard into this folder, keeping its content ('' moves it to the general folder)
+    #[arg(long)]
+    pub move_to: Option<String>,
+    /// Use it to delete the dashboards whose title matches the query
+    #[arg(short, long)]
+    pub query: Option<String>,
+    /// Use it to confirm the deletion of the dashboards upfront
+    #[arg(short, long, default_value_t = false)]
+    pub yes: bool,
+}
+
+/// The available options to compare two versions of a dashboard
+#[derive(Debug, Args, Clone)]
+pub struct DashboardDiffOptions {
+    /// The unique identifier of the dashboard
+    pub uid: String,
+    /// The version to compare from
+    pub from_version: u32,
+    /// The version to compare to
+    pub to_version: u32,
 }
@@ -0,0 +1,57 @@
+use crate::api::dashboard::SaveDashboardJsonRequest;
+use crate::api::grafana::GrafanaClient;
+use crate::cli::dashboard::options::DashboardOptions;
+use crate::cli::shell::output::OutputFormat;
+use crate::error::GrafanaCliError;
+
+/// The title of the root level folder of the dashboards without a folder
+const GENERAL_FOLDER_TITLE: &str = "General";
+
+pub async fn handle_set_dashboard(grafana_client: &GrafanaClient, opt: &DashboardOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let Some(uid) = &opt.uid else {
+        return Err(GrafanaCliError::CanNotSetDashboardWithoutUid);
+    };
+    match (opt.restore, &opt.move_to) {
+        (Some(version), _) => restore_dashboard(grafana_client, uid, version, opt.message.clone(), output).await,
+        (None, Some(folder_uid)) => move_dashboard(grafana_client, uid, folder_uid, opt.message.clone(), output).await,
+        (None, None) => Err(GrafanaCliError::NothingToSetOnDashboard),
+    }
+}
+
+/// Saves the unchanged dashboard into another folder, rejected if the dashboard was changed in the meantime
+async fn move_dashboard(grafana_client: &GrafanaClient, uid: &str, folder_uid: &str, message: Option<String>, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let mut current = grafana_client.dashboards().get_json(uid).await?;
+    let from = current["meta"]["folderTitle"].as_str().filter(|title| !title.is_empty()).unwrap_or(GENERAL_FOLDER_TITLE).to_string();
+    let request = SaveDashboardJsonRequest {
+        dashboard: current["dashboard"].take(),
+        folder_uid: Some(folder_uid.to_string()).filter(|folder_uid| !folder_uid.is_empty()),
+        message: message.unwrap_or(format!("moved from folder {}", from)),
+        overwrite: false,
+    };
+    let response = grafana_client.dashboards().save_json(&request).await?;
+    let to = if folder_uid.is_empty() { GENERAL_FOLDER_TITLE } else { folder_uid };
+    output.message(format!("Dashboard moved from folder [{}] to [{}]:", from, to));
+    output.print_one(&response)?;
+    Ok(())
+}
+
+/// Saves the model of a previous version as the newest version, rejected if the dashboard was changed in the meantime
+async fn restore_dashboard(grafana_client: &GrafanaClient, uid: &str, version: u32, message: Option<String>, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let current = grafana_client.dashboards().get_json(uid).await?;
+    let mut dashboard = grafana_client.dashboards().version(uid, version).await?.data;
+    if let Some(model) = dashboard.as_object_mut() {
+        model.insert("version".to_string(), current["dashboard"]["version"].clone());
+    }
+    let folder_uid = current["meta"]["folderUid"].as_str().filter(|folder_uid| !folder_uid.is_empty()).map(str::to_string);
+    let request = SaveDashboardJsonRequest {
+        dashboard,
+        folder_uid,
+        message: message.unwrap_or(format!("restored from version {}", version)),
+        overwrite: false,
+    };
+    let response = grafana_client.dashboards().save_json(&request).await?;
+    output.message(format!("Dashboard restored from version {} as version {}:", version, response.version));
+    output.print_one(&response)?;
+    Ok(())
+}
+
@@ -1,67 +1,35 @@
-use serde::{Deserialize, Serialize};
-
+use crate::api::folder::{AddFolderRequest, AddFolderResponse};
 use crate::api::grafana::GrafanaClient;
 use crate::cli::folder::options::FolderOptions;
 use crate::cli::shell::input::prompt_option;
+use crate::cli::shell::output::{OutputFormat, Tabular};
 use crate::error::GrafanaCliError;
 
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct AddFolderRequest {
-    pub title: String,
-}
+impl Tabular for AddFolderResponse {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["ID", "UID", "TITLE", "VERSION", "URL"];
+        if wide {
+            headers.extend(["CREATED_BY", "CREATED"]);
+        }
+        headers
+    }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct AddFolderResponse {
-    pub id: u32,
-    pub uid: String,
-    pub title: String,
-    pub url: String,
-    pub has_acl: bool,
-    pub can_save: bool,
-    pub can_edit: bool,
-    pub can_admin: bool,
-    pub created_by: String,
-    pub created: String,
-    pub updated_by: String,
-    pub updated: String,
-    pub version: u32,
+    fn row(&self, wide: bool) -> Vec<String> {
+        let mut row = vec![self.id.to_string(), self.uid.clone(), self.title.clone(), self.version.to_string(), self.url.clone()];
+        if wide {
+            row.extend([self.created_by.clone(), self.created.clone()]);
+        }
+        row
+    }
 }
 
-pub async fn handle_add_folder(grafana_client: &GrafanaClient, opt: &FolderOptions) -> Result<AddFolderResponse, GrafanaCliError> {
+pub async fn handle_add_folder(grafana_client: &GrafanaClient, opt: &FolderOptions, output: OutputFormat) -> Result<AddFolderResponse, GrafanaCliError> {
     let title = prompt_option("Enter the folder title: ", &opt.title);
     if let Some(title) = title {
-        return match add_folder(grafana_client, title).await {
-            Ok(response) => {
-                println!("Folder created [uid: {}, title: {}]", response.uid, response.title);
-                println!("url: {}", response.url);
-                Ok(response)
-            }
-            Err(error) => {
-                eprintln!("{}", error);
-                Err(error)
-            }
-        };
+        let response = grafana_client.folders().create(&AddFolderRequest { uid: None, title, parent_uid: opt.parent_uid.clone() }).await?;
+        output.message("Folder created:");
+        output.print_one(&response)?;
+        return Ok(response);
     }
     Err(GrafanaCliError::CanNotAddFolderWithoutTitle)
 }
-
-async fn add_folder(grafana_client: &GrafanaClient, title: String) -> Result<AddFolderResponse, GrafanaCliError> {
-    let request = AddFolderRequest { title: title.clone() };
-    match post_add_folder(grafana_client, &request).await {
-        Ok(response) => {
-            Ok(response)
-        }
-        Err(error) => {
-            Err(GrafanaCliError::Request(error))
-        }
-    }
-}
-
-async fn post_add_folder(grafana_client: &GrafanaClient, request: &AddFolderRequest) -> Result<AddFolderResponse, reqwest::Error> {
-    match grafana_client.post("folders", request).await {
-        Ok(response) => Ok(response.json::<AddFolderResponse>().await?),
-        Err(error) => Err(error)
-    }
-}
\ No newline at end of file
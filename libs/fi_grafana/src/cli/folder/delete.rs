@@ -0,0 +1,53 @@
+use crate::api::folder::AlertRuleResponse;
+use crate::api::grafana::GrafanaClient;
+use crate::api::search::{DASH_DB_TYPE, SearchDashTypeRequest};
+use crate::cli::folder::options::FolderOptions;
+use crate::cli::shell::input::{confirm, prompt_option};
+use crate::cli::shell::output::{OutputFormat, Tabular};
+use crate::error::GrafanaCliError;
+
+impl Tabular for AlertRuleResponse {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["UID", "TITLE", "RULE_GROUP"]
+    }
+
+    fn row(&self, _wide: bool) -> Vec<String> {
+        vec![self.uid.clone(), self.title.clone(), self.rule_group.clone()]
+    }
+}
+
+pub async fn handle_del_folder(grafana_client: &GrafanaClient, opt: &FolderOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let uid = prompt_option("Enter the folder uid: ", &opt.uid).ok_or(GrafanaCliError::CanNotDeleteFolderWithoutUid)?;
+    let folder = grafana_client.folders().get(&uid).await?;
+    let root_uids = grafana_client.folders().root_uids().await?;
+    let subfolders = grafana_client.folders().list_all_subfolders(&uid, &root_uids).await?;
+    let dashboards = grafana_client.search().dash_types_all(&SearchDashTypeRequest {
+        r#type: Some(DASH_DB_TYPE.to_string()),
+        folder_uids: Some(vec![uid.clone()]),
+        ..Default::default()
+    }).await?;
+    let alert_rules = grafana_client.folders().alert_rules(&uid).await?;
+    if output.is_human() {
+        output.message(format!("Dashboards in folder [{}] ({}):", folder.title, dashboards.len()));
+        output.print(&dashboards)?;
+        output.message(format!("Alert rules in folder [{}] ({}):", folder.title, alert_rules.len()));
+        output.print(&alert_rules)?;
+    }
+    if !subfolders.is_empty() {
+        output.message(format!("The folder has {} subfolders which are deleted with their content as well", subfolders.len()));
+    }
+    if !alert_rules.is_empty() && !opt.force_delete_rules {
+        output.message("Grafana refuses to delete a folder with alert rules, use --force-delete-rules to delete them as well");
+    }
+    let prompt = format!(
+        "Delete folder [{}] with [{}] dashboards, [{}] alert rules and [{}] subfolders?",
+        folder.title, dashboards.len(), alert_rules.len(), subfolders.len()
+    );
+    if confirm(&prompt, opt.yes) {
+        let response = grafana_client.folders().delete(&uid, opt.force_delete_rules).await?;
+        output.message(format!("{} [uid: {}, title: {}]", response.message, uid, folder.title));
+    } else {
+        output.message("No delete confirmation. Skipping request..");
+    }
+    Ok(())
+}
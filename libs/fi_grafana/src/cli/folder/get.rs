@@ -1,88 +1,139 @@
-use serde::Deserialize;
+use std::collections::{HashMap, HashSet, VecDeque};
 
+use serde::Serialize;
+
+use crate::api::folder::{GetFolderResponse, QueryFolderResponse};
 use crate::api::grafana::GrafanaClient;
+use crate::api::page::DEFAULT_PAGE_SIZE;
 use crate::cli::folder::options::FolderOptions;
+use crate::cli::shell::output::{cell, OutputFormat, Tabular};
 use crate::error::GrafanaCliError;
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct QueryFolderResponse {
-    id: u32,
-    uid: String,
-    title: String,
+impl Tabular for QueryFolderResponse {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["ID", "UID", "TITLE"]
+    }
+
+    fn row(&self, _wide: bool) -> Vec<String> {
+        vec![self.id.to_string(), self.uid.clone(), self.title.clone()]
+    }
 }
 
-#[derive(Deserialize, Clone)]
+impl Tabular for GetFolderResponse {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["ID", "UID", "TITLE", "VERSION", "PARENT_UID"];
+        if wide {
+            headers.extend(["CREATED", "UPDATED", "URL"]);
+        }
+        headers
+    }
+
+    fn row(&self, wide: bool) -> Vec<String> {
+        let mut row = vec![self.id.to_string(), self.uid.clone(), self.title.clone(), self.version.to_string(), cell(&self.parent_uid)];
+        if wide {
+            row.extend([self.created.clone(), self.updated.clone(), self.url.clone()]);
+        }
+        row
+    }
+}
+
+/// A folder with its nested subfolders
+#[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct GetFolderResponse {
-    id: u32,
+pub struct FolderNode {
+    pub uid: String,
+    pub title: String,
+    pub children: Vec<FolderNode>,
+}
+
+impl Tabular for FolderNode {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["UID", "TITLE", "SUBFOLDERS"]
+    }
+
+    fn row(&self, _wide: bool) -> Vec<String> {
+        vec![self.uid.clone(), self.title.clone(), self.children.len().to_string()]
+    }
+}
+
+/// A line of the rendered folder tree
+#[derive(Serialize)]
+struct FolderTreeLine {
+    tree: String,
     uid: String,
-    title: String,
-    url: String,
-    has_acl: bool,
-    can_save: bool,
-    can_edit: bool,
-    can_admin: bool,
-    created_by: String,
-    created: String,
-    updated_by: String,
-    updated: String,
-    version: u8,
-    // if nested folders are enabled and the folder is nested
-    parent_uid: Option<String>,
-    parents: Option<Vec<String>>,
 }
 
-pub async fn handle_get_folder(grafana_client: &GrafanaClient, opt: &FolderOptions) {
-    if let Some(uid) = &opt.uid {
-        match get_folder_by_uid(grafana_client, uid.clone()).await {
-            Ok(response) => {
-                println!("Folder:");
-                println!("id: {} | uid: {} | title: {}", response.id, response.uid, response.title);
-                println!("created: {} | updated: {}", response.created, response.updated);
-                println!("url: {}", response.url);
-                println!("version: {} | parent: {:?}", response.version, response.parent_uid);
-                return;
-            }
-            Err(error) => {
-                eprintln!("{}", error);
-                return;
-            }
-        }
+impl Tabular for FolderTreeLine {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["TREE", "UID"]
     }
-    let limit = opt.limit.unwrap_or(0);
-    let page = opt.page.unwrap_or(0);
-    match query_folders(grafana_client, limit, page).await {
-        Ok(response) => {
-            println!("Folders ({}):", response.len());
-            for folder in response {
-                println!("id: {} | uid: {} | title: {}", folder.id, folder.uid, folder.title);
-            }
-        }
-        Err(error) => {
-            eprintln!("{}", error);
-        }
+
+    fn row(&self, _wide: bool) -> Vec<String> {
+        vec![self.tree.clone(), self.uid.clone()]
     }
 }
 
-async fn query_folders(grafana_client: &GrafanaClient, limit: u8, page: u8) -> Result<Vec<QueryFolderResponse>, GrafanaCliError> {
-    match grafana_client.query("folders", &[("limit", limit), ("page", page)]).await {
-        Ok(response) => {
-            Ok(response.json::<Vec<QueryFolderResponse>>().await?)
-        }
-        Err(error) => {
-            Err(GrafanaCliError::Request(error))
-        }
+pub async fn handle_get_folder(grafana_client: &GrafanaClient, opt: &FolderOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    if opt.tree {
+        let tree = get_folder_tree(grafana_client, opt.uid.clone()).await?;
+        return if output.is_human() {
+            let mut lines = vec![];
+            render_tree(&tree, "", &mut lines);
+            output.print(&lines)
+        } else {
+            output.print(&tree)
+        };
+    }
+    if let Some(uid) = &opt.uid {
+        let response = grafana_client.folders().get(uid).await?;
+        return output.print_one(&response);
     }
+    let response = if opt.limit.is_some() || opt.page.is_some() {
+        grafana_client.folders().list(opt.limit.unwrap_or(DEFAULT_PAGE_SIZE), opt.page.unwrap_or(1)).await?
+    } else {
+        grafana_client.folders().list_all().await?
+    };
+    output.message(format!("Folders ({}):", response.len()));
+    output.print(&response)
 }
 
-async fn get_folder_by_uid(grafana_client: &GrafanaClient, uid: String) -> Result<GetFolderResponse, GrafanaCliError> {
-    match grafana_client.get(&format!("folders/{}", uid)).await {
-        Ok(response) => {
-            Ok(response.json::<GetFolderResponse>().await?)
-        }
-        Err(error) => {
-            Err(GrafanaCliError::Request(error))
-        }
+/// Walks the nested folders level by level below the given parent (or the root level)
+pub async fn get_folder_tree(grafana_client: &GrafanaClient, parent_uid: Option<String>) -> Result<Vec<FolderNode>, GrafanaCliError> {
+    let mut children_by_parent: HashMap<Option<String>, Vec<QueryFolderResponse>> = HashMap::new();
+    let root_uids = grafana_client.folders().root_uids().await?;
+    let mut visited: HashSet<String> = parent_uid.iter().cloned().collect();
+    let mut parents = VecDeque::from([parent_uid.clone()]);
+    while let Some(parent) = parents.pop_front() {
+        let children = match &parent {
+            Some(parent) => grafana_client.folders().list_all_subfolders(parent, &root_uids).await?,
+            None => grafana_client.folders().list_all_children(None).await?,
+        };
+        // guards against a parent chain running in a circle
+        let children: Vec<QueryFolderResponse> = children.into_iter().filter(|child| visited.insert(child.uid.clone())).collect();
+        parents.extend(children.iter().map(|child| Some(child.uid.clone())));
+        children_by_parent.insert(parent, children);
     }
-}
\ No newline at end of file
+    Ok(build_tree(&mut children_by_parent, parent_uid))
+}
+
+fn build_tree(children_by_parent: &mut HashMap<Option<String>, Vec<QueryFolderResponse>>, parent_uid: Option<String>) -> Vec<FolderNode> {
+    let children = children_by_parent.remove(&parent_uid).unwrap_or_default();
+    children
+        .into_iter()
+        .map(|child| FolderNode {
+            children: build_tree(children_by_parent, Some(child.uid.clone())),
+            uid: child.uid,
+            title: child.title,
+        })
+        .collect()
+}
+
+fn render_tree(nodes: &[FolderNode], prefix: &str, lines: &mut Vec<FolderTreeLine>) {
+    for (index, node) in nodes.iter().enumerate() {
+        let is_last = index + 1 == nodes.len();
+        let branch = if is_last { "└── " } else { "├── " };
+        lines.push(FolderTreeLine { tree: format!("{}{}{}", prefix, branch, node.title), uid: node.uid.clone() });
+        let indent = if is_last { "    " } else { "│   " };
+        render_tree(&node.children, &format!("{}{}", prefix, indent), lines);
+    }
+}
@@ -1,5 +1,7 @@
 pub mod permission;
 pub mod add;
+pub mod delete;
 pub mod error;
 pub mod get;
-pub mod options;
\ No newline at end of file
+pub mod options;
+pub mod set;
@@ -3,18 +3,33 @@ use clap::Args;
 /// The available options for a folder request to grafana
 #[derive(Debug, Args, Clone)]
 pub struct FolderOptions {
-    /// Use it to get a team by its uid
+    /// Use it to get, set or delete a folder by its uid
     #[arg(short, long)]
     pub uid: Option<String>,
-    /// Use it to add a folder with this title
+    /// Use it to add a folder with this title or to rename a folder
     #[arg(short, long)]
     pub title: Option<String>,
-    /// Use it to limit number of all teams you want to get
+    /// Use it to limit the number of folders per page (otherwise all pages are fetched)
     #[arg(short, long)]
-    pub limit: Option<u8>,
-    /// Use it to get all team from a specified page
+    pub limit: Option<u32>,
+    /// Use it to get the folders of only this page (otherwise all pages are fetched)
     #[arg(short, long)]
-    pub page: Option<u8>,
+    pub page: Option<u32>,
+    /// Use it to add a folder below this parent folder or to move a folder below it ('' moves it to the root level)
+    #[arg(long)]
+    pub parent_uid: Option<String>,
+    /// Use it to rename a folder only if it still has this version (otherwise the current version is used)
+    #[arg(long)]
+    pub folder_version: Option<u32>,
+    /// Use it to get the whole nested folder hierarchy
+    #[arg(long, default_value_t = false)]
+    pub tree: bool,
+    /// Use it to delete a folder together with the alert rules stored in it
+    #[arg(long, default_value_t = false)]
+    pub force_delete_rules: bool,
+    /// Use it to confirm the deletion of the folder upfront
+    #[arg(short, long, default_value_t = false)]
+    pub yes: bool,
 }
 
 impl FolderOptions {
@@ -24,6 +39,11 @@ impl FolderOptions {
             uid: None,
             limit: None,
             page: None,
+            parent_uid: None,
+            folder_version: None,
+            tree: false,
+            force_delete_rules: false,
+            yes: false,
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,61 @@
+use crate::api::folder::{FolderPermissionItem, GetPermissionResponse};
+use crate::api::grafana::GrafanaClient;
+use crate::cli::folder::permission::options::{FolderPermissionOptions, PermissionTarget};
+use crate::cli::folder::permission::set::{own_permission_items, permission_changes};
+use crate::cli::shell::output::{OutputFormat, Tabular};
+use crate::error::GrafanaCliError;
+
+impl Tabular for GetPermissionResponse {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["ROLE", "TEAM", "USER", "PERMISSION", "INHERITED"];
+        if wide {
+            headers.extend(["TEAM_ID", "USER_ID"]);
+        }
+        headers
+    }
+
+    fn row(&self, wide: bool) -> Vec<String> {
+        let text = |value: &str| if value.is_empty() { "-".to_string() } else { value.to_string() };
+        let mut row = vec![
+            text(self.role.as_deref().unwrap_or_default()),
+            text(&self.team),
+            text(&self.user_login),
+            text(&self.permission_name),
+            self.inherited.to_string(),
+        ];
+        if wide {
+            row.extend([self.team_id.to_string(), self.user_id.to_string()]);
+        }
+        row
+    }
+}
+
+pub async fn handle_get_folder_permissions(grafana_client: &GrafanaClient, opt: &FolderPermissionOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let target = opt.target()?;
+    if let (true, PermissionTarget::Dashboard(dashboard_uid)) = (opt.compare_folder, &target) {
+        return print_divergence_from_folder(grafana_client, dashboard_uid, output).await;
+    }
+    let permissions = target.permissions(grafana_client).await?;
+    output.message(format!("Permissions of {} ({}):", target, permissions.len()));
+    output.print(&permissions)
+}
+
+/// Prints the entries set on the dashboard itself that the folder does not grant (+) or grants with another level (~)
+async fn print_divergence_from_folder(grafana_client: &GrafanaClient, dashboard_uid: &str, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let folder_uid = grafana_client.dashboards().folder_uid(dashboard_uid).await?;
+    // the general folder has no access control list of its own
+    let folder_items: Vec<FolderPermissionItem> = if folder_uid.is_empty() {
+        vec![]
+    } else {
+        grafana_client.folders().permissions(&folder_uid).await?.iter().map(|permission| permission.item()).collect()
+    };
+    let dashboard_items = own_permission_items(grafana_client, &PermissionTarget::Dashboard(dashboard_uid.to_string())).await?;
+    let divergence: Vec<_> = permission_changes(&folder_items, &dashboard_items)
+        .into_iter()
+        .filter(|change| change.change != "-")
+        .collect();
+    let folder = if folder_uid.is_empty() { "General" } else { folder_uid.as_str() };
+    output.message(format!("Entries of dashboard [{}] diverging from folder [{}] ({}):", dashboard_uid, folder, divergence.len()));
+    output.message("+ granted on the dashboard only, ~ granted with another level (BEFORE = folder, AFTER = dashboard)");
+    output.print(&divergence)
+}
@@ -1,16 +1,114 @@
+use std::fmt::{Display, Formatter};
+
 use clap::Args;
 
-/// The available options for folder permissions requests
+use crate::api::folder::{BasicRole, FolderPermissionItem, GetPermissionResponse, PermissionLevel, SetFolderPermissionRequest, SetFolderPermissionResponse};
+use crate::api::grafana::GrafanaClient;
+use crate::cli::shell::input::prompt_option;
+use crate::error::GrafanaCliError;
+
+/// The available options for folder (or dashboard) permissions requests
 #[derive(Debug, Args, Clone)]
 pub struct FolderPermissionOptions {
-    /// Use it to set (override) permissions on the folder with this uid
+    /// Use it to get or set permissions on the folder with this uid
     #[arg(short, long)]
     pub folder_uid: Option<String>,
-    /// Use it to set (override) the permissions on the folder for only this team
+    /// Use it to get or set permissions on the dashboard with this uid instead of a folder
+    #[arg(short, long, conflicts_with = "folder_uid")]
+    pub dashboard_uid: Option<String>,
+    /// Use it to get the entries of the dashboard given by --dashboard-uid that diverge from its folder
+    #[arg(long, default_value_t = false, requires = "dashboard_uid")]
+    pub compare_folder: bool,
+    /// Use it to set the permissions on the folder for this team by its id or name (repeatable)
     #[arg(short, long)]
-    pub team_id: Option<u32>,
-    /// Use it to set (override) the permissions level on the folder for the team
-    /// Viewer = 1, Editor = 2, Admin = 4
+    pub team: Vec<String>,
+    /// Use it to set the permissions on the folder for this user by its id, login or email (repeatable)
     #[arg(short, long)]
-    pub permission: Option<u8>,
-}
\ No newline at end of file
+    pub user: Vec<String>,
+    /// Use it to set the permissions on the folder for this basic role (repeatable)
+    #[arg(long, value_enum)]
+    pub role: Vec<BasicRole>,
+    /// Use it to set the permissions level on the folder for the given teams, users and roles
+    #[arg(short, long, value_enum)]
+    pub permission: Option<PermissionLevel>,
+    /// Use it to add or change only the given entries while preserving the rest
+    /// (otherwise the permissions are overridden, folders keep the default Viewer/Editor/Admin entries)
+    #[arg(short, long, default_value_t = false)]
+    pub merge: bool,
+    /// Use it to remove the given entries while preserving the rest
+    #[arg(short, long, default_value_t = false)]
+    pub remove: bool,
+    /// Use it to replace all entries by the default Viewer/Editor/Admin ones of a folder (or none for a dashboard)
+    #[arg(long, default_value_t = false, conflicts_with_all = ["merge", "remove"])]
+    pub reset: bool,
+}
+
+/// The folder or dashboard whose access control list is read or updated
+#[derive(Debug, Clone)]
+pub enum PermissionTarget {
+    Folder(String),
+    Dashboard(String),
+}
+
+impl PermissionTarget {
+    pub async fn permissions(&self, grafana_client: &GrafanaClient) -> Result<Vec<GetPermissionResponse>, GrafanaCliError> {
+        match self {
+            PermissionTarget::Folder(uid) => grafana_client.folders().permissions(uid).await,
+            PermissionTarget::Dashboard(uid) => grafana_client.dashboards().permissions(uid).await,
+        }
+    }
+
+    pub async fn set_permissions(&self, grafana_client: &GrafanaClient, request: &SetFolderPermissionRequest) -> Result<SetFolderPermissionResponse, GrafanaCliError> {
+        match self {
+            PermissionTarget::Folder(uid) => grafana_client.folders().set_permissions(uid, request).await,
+            PermissionTarget::Dashboard(uid) => grafana_client.dashboards().set_permissions(uid, request).await,
+        }
+    }
+}
+
+impl Display for PermissionTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PermissionTarget::Folder(uid) => write!(f, "folder [{}]", uid),
+            PermissionTarget::Dashboard(uid) => write!(f, "dashboard [{}]", uid),
+        }
+    }
+}
+
+impl FolderPermissionOptions {
+    /// The dashboard given by --dashboard-uid, otherwise the folder given by --folder-uid (prompted if missing)
+    pub fn target(&self) -> Result<PermissionTarget, GrafanaCliError> {
+        if let Some(dashboard_uid) = &self.dashboard_uid {
+            return Ok(PermissionTarget::Dashboard(dashboard_uid.clone()));
+        }
+        prompt_option("Enter the folder_uid: ", &self.folder_uid)
+            .map(PermissionTarget::Folder)
+            .ok_or(GrafanaCliError::CanNotUpdatePermissionsOnNonExistingFolder)
+    }
+
+    /// The entries for the given teams, users and roles with team names and user logins resolved to ids
+    pub async fn grants(&self, grafana_client: &GrafanaClient) -> Result<Vec<FolderPermissionItem>, GrafanaCliError> {
+        let has_grantees = !self.team.is_empty() || !self.user.is_empty() || !self.role.is_empty();
+        // without grantees an override would wipe the existing entries, which has to be asked for explicitly
+        if !has_grantees && !self.reset {
+            return Err(GrafanaCliError::CanNotSetPermissionsWithoutGrantees);
+        }
+        let permission = match self.permission {
+            Some(permission) => permission.value(),
+            // the permission level does not matter for a removal or a reset
+            None if self.remove || !has_grantees => 0,
+            None => return Err(GrafanaCliError::CanNotGrantPermissionsWithoutLevel),
+        };
+        let mut grants = vec![];
+        for team in &self.team {
+            grants.push(FolderPermissionItem::team(grafana_client.teams().resolve_id(team).await?, permission));
+        }
+        for user in &self.user {
+            grants.push(FolderPermissionItem::user(grafana_client.users().resolve_id(user).await?, permission));
+        }
+        for role in &self.role {
+            grants.push(FolderPermissionItem::role(role.as_str(), permission));
+        }
+        Ok(grants)
+    }
+}
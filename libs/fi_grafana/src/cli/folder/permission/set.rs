@@ -1,105 +1,191 @@
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 
+use crate::api::folder::{FolderPermissionItem, PermissionLevel, SetFolderPermissionRequest, SetFolderPermissionResponse};
 use crate::api::grafana::GrafanaClient;
-use crate::cli::folder::permission::options::FolderPermissionOptions;
+use crate::cli::folder::permission::options::{FolderPermissionOptions, PermissionTarget};
 use crate::cli::shell::input::prompt_option;
+use crate::cli::shell::output::{cell, OutputFormat, Tabular};
 use crate::error::GrafanaCliError;
 
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SetFolderPermissionRequest {
-    pub items: Vec<FolderPermissionItem>,
+impl Tabular for FolderPermissionItem {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["ROLE", "TEAM_ID", "USER_ID", "PERMISSION"]
+    }
+
+    fn row(&self, _wide: bool) -> Vec<String> {
+        vec![cell(&self.role), cell(&self.team_id), cell(&self.user_id), PermissionLevel::name(self.permission)]
+    }
 }
 
-#[derive(Debug, Serialize, Clone)]
+/// An entry that is added, changed or removed by a permissions update
+#[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct FolderPermissionItem {
+pub struct PermissionChange {
+    /// + added, ~ changed, - removed
+    pub change: String,
     pub role: Option<String>,
     pub team_id: Option<u32>,
     pub user_id: Option<u32>,
-    pub permission: u8,
+    pub before: Option<u8>,
+    pub after: Option<u8>,
 }
 
-impl FolderPermissionItem {
-    pub fn default_permissions_items() -> Vec<FolderPermissionItem> {
-        let viewer = FolderPermissionItem::role("Viewer", 1);
-        let editor = FolderPermissionItem::role("Editor", 2);
-        let admin = FolderPermissionItem::role("Admin", 4);
-        vec![viewer, editor, admin]
+impl Tabular for PermissionChange {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["CHANGE", "ROLE", "TEAM_ID", "USER_ID", "BEFORE", "AFTER"]
     }
 
-    pub fn role(role: &str, permission: u8) -> Self {
-        Self {
-            role: Some(role.to_string()),
-            team_id: None,
-            user_id: None,
-            permission,
-        }
+    fn row(&self, _wide: bool) -> Vec<String> {
+        let level = |permission: &Option<u8>| cell(&permission.map(PermissionLevel::name));
+        vec![self.change.clone(), cell(&self.role), cell(&self.team_id), cell(&self.user_id), level(&self.before), level(&self.after)]
     }
+}
 
-    pub fn team(team_id: u32, permission: u8) -> Self {
-        Self {
-            role: None,
-            team_id: Some(team_id),
-            user_id: None,
-            permission,
-        }
+pub async fn handle_set_folder_permissions(grafana_client: &GrafanaClient, opt: &FolderPermissionOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let target = opt.target()?;
+    let grants = opt.grants(grafana_client).await?;
+    let before = own_permission_items(grafana_client, &target).await?;
+    let after = if opt.merge || opt.remove {
+        merge_permission_items(&before, &grants, opt.remove)
+    } else if let PermissionTarget::Folder(_) = target {
+        merge_permission_items(&FolderPermissionItem::default_permissions_items(), &grants, false)
+    } else {
+        grants
+    };
+    if !print_permission_changes(&before, &after, output)? {
+        return Ok(());
     }
+    let response = target.set_permissions(grafana_client, &SetFolderPermissionRequest { items: after.clone() }).await?;
+    output.message(format!("{} {}", response.message, target));
+    output.print(&after)
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SetFolderPermissionResponse {
-    pub message: String,
+/// The entries set on the folder or dashboard itself, inherited entries come from the parent folders and can not be set here
+pub async fn own_permission_items(grafana_client: &GrafanaClient, target: &PermissionTarget) -> Result<Vec<FolderPermissionItem>, GrafanaCliError> {
+    Ok(target.permissions(grafana_client).await?
+        .iter()
+        .filter(|permission| !permission.inherited)
+        .map(|permission| permission.item())
+        .collect())
+}
+
+/// Adds or replaces (or removes) the entries of the grantees of the given items and keeps all other entries
+pub fn merge_permission_items(items: &[FolderPermissionItem], changes: &[FolderPermissionItem], remove: bool) -> Vec<FolderPermissionItem> {
+    let mut merged: Vec<FolderPermissionItem> = items
+        .iter()
+        .filter(|item| !changes.iter().any(|change| change.same_grantee(item)))
+        .cloned()
+        .collect();
+    if !remove {
+        merged.extend(changes.iter().cloned());
+    }
+    merged
 }
 
-pub async fn handle_set_folder_permissions(grafana_client: &GrafanaClient, opt: &FolderPermissionOptions) {
-    let mut items = FolderPermissionItem::default_permissions_items();
-    if let Some(team_id) = opt.team_id {
-        if let Some(permission) = opt.permission {
-            items.push(FolderPermissionItem::team(team_id, permission));
+/// The entries that differ between the access control lists before and after an update
+pub fn permission_changes(before: &[FolderPermissionItem], after: &[FolderPermissionItem]) -> Vec<PermissionChange> {
+    let change = |sign: &str, item: &FolderPermissionItem, before: Option<u8>, after: Option<u8>| PermissionChange {
+        change: sign.to_string(),
+        role: item.role.clone(),
+        team_id: item.team_id,
+        user_id: item.user_id,
+        before,
+        after,
+    };
+    let mut changes = vec![];
+    for item in after {
+        match before.iter().find(|old| old.same_grantee(item)) {
+            None => changes.push(change("+", item, None, Some(item.permission))),
+            Some(old) if old.permission != item.permission => changes.push(change("~", item, Some(old.permission), Some(item.permission))),
+            Some(_) => {}
         }
     }
-    match set_folder_permissions(grafana_client, opt.folder_uid.clone(), items).await {
-        Ok(_) => {}
-        Err(_) => {}
+    for old in before.iter().filter(|old| !after.iter().any(|item| item.same_grantee(old))) {
+        changes.push(change("-", old, Some(old.permission), None));
     }
+    changes
 }
 
-pub async fn set_folder_permissions(grafana_client: &GrafanaClient, folder_uid: Option<String>, items: Vec<FolderPermissionItem>) -> Result<SetFolderPermissionResponse, GrafanaCliError> {
+/// Prints the before/after diff for review, false if there is nothing to change
+pub fn print_permission_changes(before: &[FolderPermissionItem], after: &[FolderPermissionItem], output: OutputFormat) -> Result<bool, GrafanaCliError> {
+    let changes = permission_changes(before, after);
+    if changes.is_empty() {
+        output.message("No permission changes. Skipping request..");
+        return Ok(false);
+    }
+    output.message(format!("Permission changes ({}):", changes.len()));
+    output.print(&changes)?;
+    Ok(true)
+}
+
+pub async fn set_folder_permissions(grafana_client: &GrafanaClient, folder_uid: Option<String>, items: Vec<FolderPermissionItem>, output: OutputFormat) -> Result<SetFolderPermissionResponse, GrafanaCliError> {
     let update = SetFolderPermissionRequest { items: items.clone() };
     let folder_uid = prompt_option("Enter the folder_uid: ", &folder_uid);
     if let Some(folder_uid) = folder_uid {
-        match post_set_folder_permissions(grafana_client, &update, folder_uid.clone()).await {
-            Ok(response) => {
-                println!("{} [uid: {}]", response.message, folder_uid);
-                for item in items {
-                    if let Some(role) = item.role {
-                        print!("role: {} ", role);
-                    }
-                    if let Some(team_id) = item.team_id {
-                        print!("team_id: {} ", team_id);
-                    }
-                    if let Some(user_id) = item.user_id {
-                        print!("user_id: {} ", user_id);
-                    }
-                    println!("permission: {}", item.permission);
-                }
-                Ok(response)
-            }
-            Err(error) => {
-                eprintln!("{}", error);
-                Err(GrafanaCliError::Request(error))
-            }
-        }
+        let response = grafana_client.folders().set_permissions(&folder_uid, &update).await?;
+        output.message(format!("{} [uid: {}]", response.message, folder_uid));
+        output.print(&items)?;
+        Ok(response)
     } else {
         Err(GrafanaCliError::CanNotUpdatePermissionsOnNonExistingFolder)
     }
 }
 
-async fn post_set_folder_permissions(grafana_client: &GrafanaClient, request: &SetFolderPermissionRequest, folder_uid: String) -> Result<SetFolderPermissionResponse, reqwest::Error> {
-    match grafana_client.post(&format!("folders/{}/permissions", folder_uid), request).await {
-        Ok(response) => Ok(response.json::<SetFolderPermissionResponse>().await?),
-        Err(error) => Err(error)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn viewer() -> FolderPermissionItem {
+        FolderPermissionItem::role("Viewer", 1)
+    }
+
+    fn signs(changes: &[PermissionChange]) -> Vec<(&str, Option<u32>, Option<u32>)> {
+        changes.iter().map(|change| (change.change.as_str(), change.team_id, change.user_id)).collect()
+    }
+
+    #[test]
+    fn merge_adds_new_grantees_and_keeps_the_others() {
+        let merged = merge_permission_items(&[viewer(), FolderPermissionItem::team(1, 1)], &[FolderPermissionItem::user(7, 2)], false);
+        assert_eq!(merged.len(), 3);
+        assert!(merged.iter().any(|item| item.same_grantee(&viewer()) && item.permission == 1));
+        assert!(merged.iter().any(|item| item.team_id == Some(1) && item.permission == 1));
+        assert!(merged.iter().any(|item| item.user_id == Some(7) && item.permission == 2));
+    }
+
+    #[test]
+    fn merge_replaces_the_entry_of_the_same_grantee() {
+        let merged = merge_permission_items(&[viewer(), FolderPermissionItem::team(1, 1)], &[FolderPermissionItem::team(1, 4)], false);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged.iter().find(|item| item.team_id == Some(1)).unwrap().permission, 4);
+    }
+
+    #[test]
+    fn merge_tells_team_and_user_with_the_same_id_apart() {
+        let merged = merge_permission_items(&[FolderPermissionItem::team(3, 1)], &[FolderPermissionItem::user(3, 2)], false);
+        assert_eq!(merged.len(), 2);
+    }
+
+    #[test]
+    fn remove_drops_the_grantees_regardless_of_the_level() {
+        let merged = merge_permission_items(&[viewer(), FolderPermissionItem::team(1, 1), FolderPermissionItem::user(7, 2)], &[FolderPermissionItem::team(1, 4), FolderPermissionItem::user(7, 1)], true);
+        assert_eq!(merged.len(), 1);
+        assert!(merged[0].same_grantee(&viewer()));
+    }
+
+    #[test]
+    fn remove_of_an_unknown_grantee_changes_nothing() {
+        let items = [viewer(), FolderPermissionItem::team(1, 1)];
+        let merged = merge_permission_items(&items, &[FolderPermissionItem::user(9, 1)], true);
+        assert!(permission_changes(&items, &merged).is_empty());
+    }
+
+    #[test]
+    fn changes_list_added_changed_and_removed_entries() {
+        let before = [viewer(), FolderPermissionItem::team(1, 1), FolderPermissionItem::user(7, 2)];
+        let after = [viewer(), FolderPermissionItem::team(1, 4), FolderPermissionItem::user(8, 1)];
+        let changes = permission_changes(&before, &after);
+        assert_eq!(signs(&changes), vec![("~", Some(1), None), ("+", None, Some(8)), ("-", None, Some(7))]);
+        assert_eq!((changes[0].before, changes[0].after), (Some(1), Some(4)));
+        assert_eq!((changes[2].before, changes[2].after), (Some(2), None));
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,32 @@
+use crate::api::folder::{MoveFolderRequest, UpdateFolderRequest};
+use crate::api::grafana::GrafanaClient;
+use crate::cli::folder::options::FolderOptions;
+use crate::cli::shell::input::prompt_option;
+use crate::cli::shell::output::OutputFormat;
+use crate::error::GrafanaCliError;
+
+pub async fn handle_set_folder(grafana_client: &GrafanaClient, opt: &FolderOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let uid = prompt_option("Enter the folder uid: ", &opt.uid).ok_or(GrafanaCliError::CanNotSetFolderWithoutUid)?;
+    if opt.title.is_none() && opt.parent_uid.is_none() {
+        output.message("Nothing to set, use --title to rename or --parent-uid to move the folder");
+        return Ok(());
+    }
+    if let Some(title) = &opt.title {
+        let version = match opt.folder_version {
+            Some(version) => version,
+            None => grafana_client.folders().get(&uid).await?.version,
+        };
+        // grafana answers with 412 (exit code 7) if the folder was changed in the meantime
+        let request = UpdateFolderRequest { title: title.clone(), version };
+        let response = grafana_client.folders().update(&uid, &request).await?;
+        output.message("Folder renamed:");
+        output.print_one(&response)?;
+    }
+    if let Some(parent_uid) = &opt.parent_uid {
+        let request = MoveFolderRequest { parent_uid: parent_uid.clone() };
+        let response = grafana_client.folders().move_to(&uid, &request).await?;
+        output.message("Folder moved:");
+        output.print_one(&response)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use crate::api::annotation::{AddAnnotationRequest, AnnotationType, FindAnnotationsRequest, GetAnnotationResponse, PatchAnnotationRequest};
+use crate::cli::migrate::options::{ConflictPolicy, MigrateResource};
+use crate::cli::migrate::report::{sent, MigrationItem, MigrationResult};
+use crate::cli::migrate::run::Migration;
+use crate::error::GrafanaCliError;
+
+/// Annotations have no uid, the same text at the same time on the same panel is taken as the same annotation
+type AnnotationKey = (Option<String>, u32, i64, i64, String);
+
+fn key(dashboard_uid: Option<String>, annotation: &GetAnnotationResponse) -> AnnotationKey {
+    (dashboard_uid, annotation.panel_id, annotation.time, annotation.time_end, annotation.text.clone())
+}
+
+/// The dashboard of the annotation, none for an organizational annotation
+fn dashboard_uid(annotation: &GetAnnotationResponse) -> Option<String> {
+    annotation.dashboard_uid.clone().filter(|uid| !uid.is_empty())
+}
+
+impl Migration<'_> {
+    /// Copies the organizational annotations and the ones of the migrated dashboards (annotations of alerts are left out)
+    pub async fn migrate_annotations(&mut self) -> Result<(), GrafanaCliError> {
+        let request = FindAnnotationsRequest { r#type: Some(AnnotationType::Annotation), ..Default::default() };
+        let existing: HashMap<AnnotationKey, GetAnnotationResponse> = self.target.annotations().find_all(&request).await?
+            .into_iter()
+            .map(|annotation| (key(dashboard_uid(&annotation), &annotation), annotation))
+            .collect();
+        for annotation in self.source.annotations().find_all(&request).await? {
+            let item = match self.migrate_annotation(&annotation, &existing).await {
+                Ok(item) => item,
+                Err(error) => MigrationItem::failed(MigrateResource::Annotations, annotation.id.to_string(), &error),
+            };
+            self.push(item);
+        }
+        Ok(())
+    }
+
+    async fn migrate_annotation(&self, annotation: &GetAnnotationResponse, existing: &HashMap<AnnotationKey, GetAnnotationResponse>) -> Result<MigrationItem, GrafanaCliError> {
+        let source = annotation.id.to_string();
+        let target_dashboard_uid = match dashboard_uid(annotation) {
+            Some(uid) => match self.target_dashboard_uid(&uid).filter(|target_uid| !target_uid.is_empty()) {
+                Some(target_uid) => Some(target_uid),
+                None => return Ok(MigrationItem::new(MigrateResource::Annotations, source, "", MigrationResult::Skipped, format!("the dashboard {} was not migrated", uid))),
+            },
+            None => None,
+        };
+        if let Some(existing) = existing.get(&key(target_dashboard_uid.clone(), annotation)) {
+            let target = existing.id.to_string();
+            if self.opt.conflict != ConflictPolicy::Overwrite || existing.tags == annotation.tags {
+                return Ok(MigrationItem::new(MigrateResource::Annotations, source, target, MigrationResult::Skipped, "the annotation exists"));
+            }
+            let request = PatchAnnotationRequest { tags: Some(annotation.tags.clone()), ..Default::default() };
+            sent(self.target.annotations().update(existing.id, &request).await)?;
+            return Ok(MigrationItem::new(MigrateResource::Annotations, source, target, MigrationResult::Updated, annotation.tags.join(",")));
+        }
+        let request = AddAnnotationRequest {
+            panel_id: target_dashboard_uid.as_ref().map(|_| annotation.panel_id).filter(|panel_id| *panel_id > 0),
+            dashboard_uid: target_dashboard_uid,
+            time: Some(annotation.time),
+            time_end: Some(annotation.time_end),
+            tags: annotation.tags.clone(),
+            text: annotation.text.clone(),
+        };
+        let target = sent(self.target.annotations().create(&request).await)?
+            .and_then(|response| response.id)
+            .map(|id| id.to_string())
+            .unwrap_or_default();
+        Ok(MigrationItem::new(MigrateResource::Annotations, source, target, MigrationResult::Created, annotation.text.clone()))
+    }
+}
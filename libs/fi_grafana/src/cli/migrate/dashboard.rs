@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::api::dashboard::SaveDashboardJsonRequest;
+use crate::api::search::{SearchDashTypeRequest, DASH_DB_TYPE};
+use crate::cli::folder::permission::options::PermissionTarget;
+use crate::cli::migrate::options::{ConflictPolicy, MigrateResource};
+use crate::cli::migrate::report::{sent, MigrationItem, MigrationResult, RENAME_SUFFIX};
+use crate::cli::migrate::run::Migration;
+use crate::error::GrafanaCliError;
+
+impl Migration<'_> {
+    /// Copies the complete dashboard models into the migrated folders
+    pub async fn migrate_dashboards(&mut self) -> Result<(), GrafanaCliError> {
+        let request = SearchDashTypeRequest { r#type: Some(DASH_DB_TYPE.to_string()), ..Default::default() };
+        for uid in self.source.search().dash_type_uids(&request).await? {
+            let item = match self.migrate_dashboard(&uid).await {
+                Ok(item) => item,
+                Err(error) => MigrationItem::failed(MigrateResource::Dashboards, &uid, &error),
+            };
+            self.push(item);
+        }
+        Ok(())
+    }
+
+    async fn migrate_dashboard(&mut self, uid: &str) -> Result<MigrationItem, GrafanaCliError> {
+        let mut response = self.source.dashboards().get_json(uid).await?;
+        let folder_uid = self.target_folder_uid(response["meta"]["folderUid"].as_str().unwrap_or_default());
+        let mut dashboard = response["dashboard"].take();
+        let title = dashboard["title"].as_str().unwrap_or(uid).to_string();
+        if let Some(model) = dashboard.as_object_mut() {
+            model.remove("id");
+            if self.opt.new_uids {
+                model.remove("uid");
+            }
+        }
+        replace_library_panel_uids(&mut dashboard, &self.library_panel_uids);
+        let exists = !self.opt.new_uids && match self.target.dashboards().get_json(uid).await {
+            Ok(_) => true,
+            Err(GrafanaCliError::NotFound(_)) => false,
+            Err(error) => return Err(error),
+        };
+        let mut result = MigrationResult::Created;
+        if !exists {
+            match self.save_dashboard(dashboard.clone(), folder_uid.clone(), false).await {
+                Ok(target_uid) => return Ok(self.dashboard_item(uid, target_uid, result, title)),
+                // with new uids a dashboard with the same title in the folder is the conflict
+                Err(GrafanaCliError::PreconditionFailed(_)) => {}
+                Err(error) => return Err(error),
+            }
+        }
+        match self.opt.conflict {
+            ConflictPolicy::Skip => {
+                let existing_uid = if exists { uid.to_string() } else { String::new() };
+                if exists {
+                    self.dashboard_uids.insert(uid.to_string(), existing_uid.clone());
+                }
+                return Ok(MigrationItem::new(MigrateResource::Dashboards, uid, existing_uid, MigrationResult::Skipped, "the dashboard exists"));
+            }
+            ConflictPolicy::Overwrite => result = MigrationResult::Updated,
+            ConflictPolicy::Rename => {
+                result = MigrationResult::Renamed;
+                if let Some(model) = dashboard.as_object_mut() {
+                    model.remove("uid");
+                    model.insert("title".to_string(), Value::String(format!("{}{}", title, RENAME_SUFFIX)));
+                }
+            }
+        }
+        let target_uid = self.save_dashboard(dashboard, folder_uid, result == MigrationResult::Updated).await?;
+        Ok(self.dashboard_item(uid, target_uid, result, title))
+    }
+
+    /// Saves the dashboard and answers its uid on the target
+    async fn save_dashboard(&self, dashboard: Value, folder_uid: Option<String>, overwrite: bool) -> Result<String, GrafanaCliError> {
+        let uid = dashboard["uid"].as_str().unwrap_or_default().to_string();
+        let request = SaveDashboardJsonRequest {
+            dashboard,
+            folder_uid,
+            message: format!("migrated from {}", self.opt.from),
+            overwrite,
+        };
+        Ok(sent(self.target.dashboards().save_json(&request).await)?.map(|response| response.uid).unwrap_or(uid))
+    }
+
+    fn dashboard_item(&mut self, uid: &str, target_uid: String, result: MigrationResult, title: String) -> MigrationItem {
+        self.dashboard_uids.insert(uid.to_string(), target_uid.clone());
+        self.permission_targets.push((PermissionTarget::Dashboard(uid.to_string()), PermissionTarget::Dashboard(target_uid.clone())));
+        MigrationItem::new(MigrateResource::Dashboards, uid, target_uid, result, title)
+    }
+}
+
+/// Points the library panel references of the dashboard (also the ones within collapsed rows) to the migrated library panels
+fn replace_library_panel_uids(dashboard: &mut Value, library_panel_uids: &HashMap<String, String>) {
+    let Some(panels) = dashboard.get_mut("panels").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for panel in panels {
+        if let Some(uid) = panel.pointer_mut("/libraryPanel/uid") {
+            if let Some(target_uid) = uid.as_str().and_then(|source_uid| library_panel_uids.get(source_uid)) {
+                *uid = Value::String(target_uid.clone());
+            }
+        }
+        replace_library_panel_uids(panel, library_panel_uids);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::api::folder::{AddFolderRequest, MoveFolderRequest, UpdateFolderRequest};
+use crate::cli::folder::get::{get_folder_tree, FolderNode};
+use crate::cli::folder::permission::options::PermissionTarget;
+use crate::cli::migrate::options::{ConflictPolicy, MigrateResource};
+use crate::cli::migrate::report::{sent, MigrationItem, MigrationResult, RENAME_SUFFIX};
+use crate::cli::migrate::run::Migration;
+use crate::error::GrafanaCliError;
+
+impl Migration<'_> {
+    /// Copies the folders level by level so that every parent exists before its subfolders
+    pub async fn migrate_folders(&mut self) -> Result<(), GrafanaCliError> {
+        let mut folders: Vec<(FolderNode, Option<String>)> = get_folder_tree(self.source, None).await?
+            .into_iter()
+            .rev()
+            .map(|folder| (folder, None))
+            .collect();
+        while let Some((folder, parent_uid)) = folders.pop() {
+            let target_uid = match self.migrate_folder(&folder, parent_uid).await {
+                Ok((item, target_uid)) => {
+                    self.push(item);
+                    target_uid
+                }
+                Err(error) => {
+                    self.push(MigrationItem::failed(MigrateResource::Folders, &folder.uid, &error));
+                    self.skip_subfolders(&folder);
+                    continue;
+                }
+            };
+            folders.extend(folder.children.into_iter().rev().map(|child| (child, Some(target_uid.clone()))));
+        }
+        Ok(())
+    }
+
+    fn skip_subfolders(&mut self, folder: &FolderNode) {
+        for child in &folder.children {
+            self.push(MigrationItem::new(MigrateResource::Folders, &child.uid, "", MigrationResult::Skipped, format!("the parent folder {} was not migrated", folder.uid)));
+            self.skip_subfolders(child);
+        }
+    }
+
+    async fn migrate_folder(&mut self, folder: &FolderNode, parent_uid: Option<String>) -> Result<(MigrationItem, String), GrafanaCliError> {
+        let existing = self.existing_folder_uid(folder, parent_uid.as_deref()).await?;
+        let (title, result) = match (&existing, self.opt.conflict) {
+            (Some(existing_uid), ConflictPolicy::Skip) => {
+                self.folder_uids.insert(folder.uid.clone(), existing_uid.clone());
+                let item = MigrationItem::new(MigrateResource::Folders, &folder.uid, existing_uid, MigrationResult::Skipped, "the folder exists");
+                return Ok((item, existing_uid.clone()));
+            }
+            (Some(existing_uid), ConflictPolicy::Overwrite) => {
+                self.overwrite_folder(existing_uid, &folder.title, parent_uid).await?;
+                self.add_folder_permission_target(folder, existing_uid);
+                let item = MigrationItem::new(MigrateResource::Folders, &folder.uid, existing_uid, MigrationResult::Updated, &folder.title);
+                return Ok((item, existing_uid.clone()));
+            }
+            (Some(_), ConflictPolicy::Rename) => (format!("{}{}", folder.title, RENAME_SUFFIX), MigrationResult::Renamed),
+            (None, _) => (folder.title.clone(), MigrationResult::Created),
+        };
+        let uid = if existing.is_some() { None } else { self.new_uid(&folder.uid) };
+        let request = AddFolderRequest { uid: uid.clone(), title: title.clone(), parent_uid };
+        let target_uid = match sent(self.target.folders().create(&request).await)? {
+            Some(response) => response.uid,
+            // a dry run goes on with the uid the folder would get
+            None => uid.unwrap_or(folder.uid.clone()),
+        };
+        self.add_folder_permission_target(folder, &target_uid);
+        Ok((MigrationItem::new(MigrateResource::Folders, &folder.uid, &target_uid, result, title), target_uid))
+    }
+
+    /// The folder on the target with the same uid, with new uids the folder with the same title below the same parent
+    async fn existing_folder_uid(&self, folder: &FolderNode, parent_uid: Option<&str>) -> Result<Option<String>, GrafanaCliError> {
+        if self.opt.new_uids {
+            let siblings = self.target.folders().list_all_children(parent_uid).await?;
+            return Ok(siblings.into_iter().find(|sibling| sibling.title == folder.title).map(|sibling| sibling.uid));
+        }
+        match self.target.folders().get(&folder.uid).await {
+            Ok(existing) => Ok(Some(existing.uid)),
+            Err(GrafanaCliError::NotFound(_)) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Gives the existing folder the title and the parent of the source folder
+    async fn overwrite_folder(&self, uid: &str, title: &str, parent_uid: Option<String>) -> Result<(), GrafanaCliError> {
+        let existing = self.target.folders().get(uid).await?;
+        if existing.title != title {
+            sent(self.target.folders().update(uid, &UpdateFolderRequest { title: title.to_string(), version: existing.version }).await)?;
+        }
+        if existing.parent_uid.filter(|parent| !parent.is_empty()) != parent_uid {
+            sent(self.target.folders().move_to(uid, &MoveFolderRequest { parent_uid: parent_uid.unwrap_or_default() }).await)?;
+        }
+        Ok(())
+    }
+
+    fn add_folder_permission_target(&mut self, folder: &FolderNode, target_uid: &str) {
+        self.folder_uids.insert(folder.uid.clone(), target_uid.to_string());
+        self.permission_targets.push((PermissionTarget::Folder(folder.uid.clone()), PermissionTarget::Folder(target_uid.to_string())));
+    }
+}
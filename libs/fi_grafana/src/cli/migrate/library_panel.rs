@@ -0,0 +1,60 @@
+use crate::api::library_panel::{AddLibraryPanelRequest, GetLibraryPanelResponse, UpdateLibraryPanelRequest};
+use crate::cli::migrate::options::{ConflictPolicy, MigrateResource};
+use crate::cli::migrate::report::{sent, MigrationItem, MigrationResult, RENAME_SUFFIX};
+use crate::cli::migrate::run::Migration;
+use crate::error::GrafanaCliError;
+
+impl Migration<'_> {
+    /// Copies the library panels into the migrated folders before the dashboards using them
+    pub async fn migrate_library_panels(&mut self) -> Result<(), GrafanaCliError> {
+        for panel in self.source.library_panels().list_all().await? {
+            let item = match self.migrate_library_panel(&panel).await {
+                Ok(item) => item,
+                Err(error) => MigrationItem::failed(MigrateResource::LibraryPanels, &panel.uid, &error),
+            };
+            self.push(item);
+        }
+        Ok(())
+    }
+
+    async fn migrate_library_panel(&mut self, panel: &GetLibraryPanelResponse) -> Result<MigrationItem, GrafanaCliError> {
+        let folder_uid = self.target_folder_uid(&panel.folder_uid);
+        let existing = if self.opt.new_uids {
+            None
+        } else {
+            match self.target.library_panels().get(&panel.uid).await {
+                Ok(existing) => Some(existing),
+                Err(GrafanaCliError::NotFound(_)) => None,
+                Err(error) => return Err(error),
+            }
+        };
+        let (name, result) = match (&existing, self.opt.conflict) {
+            (Some(existing), ConflictPolicy::Skip) => {
+                self.library_panel_uids.insert(panel.uid.clone(), existing.uid.clone());
+                return Ok(MigrationItem::new(MigrateResource::LibraryPanels, &panel.uid, &existing.uid, MigrationResult::Skipped, "the library panel exists"));
+            }
+            (Some(existing), ConflictPolicy::Overwrite) => {
+                let request = UpdateLibraryPanelRequest {
+                    folder_uid,
+                    name: panel.name.clone(),
+                    model: panel.model.clone(),
+                    kind: panel.kind,
+                    version: existing.version,
+                };
+                sent(self.target.library_panels().update(&existing.uid, &request).await)?;
+                self.library_panel_uids.insert(panel.uid.clone(), existing.uid.clone());
+                return Ok(MigrationItem::new(MigrateResource::LibraryPanels, &panel.uid, &existing.uid, MigrationResult::Updated, &panel.name));
+            }
+            (Some(_), ConflictPolicy::Rename) => (format!("{}{}", panel.name, RENAME_SUFFIX), MigrationResult::Renamed),
+            (None, _) => (panel.name.clone(), MigrationResult::Created),
+        };
+        let uid = if existing.is_some() { None } else { self.new_uid(&panel.uid) };
+        let request = AddLibraryPanelRequest { uid: uid.clone(), folder_uid, name: name.clone(), model: panel.model.clone(), kind: panel.kind };
+        let target_uid = match sent(self.target.library_panels().create(&request).await)? {
+            Some(response) => response.uid,
+            None => uid.unwrap_or(panel.uid.clone()),
+        };
+        self.library_panel_uids.insert(panel.uid.clone(), target_uid.clone());
+        Ok(MigrationItem::new(MigrateResource::LibraryPanels, &panel.uid, target_uid, result, name))
+    }
+}
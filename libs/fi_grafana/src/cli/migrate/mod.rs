@@ -0,0 +1,9 @@
+pub mod annotation;
+pub mod dashboard;
+pub mod folder;
+pub mod library_panel;
+pub mod options;
+pub mod permission;
+pub mod report;
+pub mod run;
+pub mod team;
@@ -0,0 +1,64 @@
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+
+/// The kinds of resources copied by a migration
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum MigrateResource {
+    Folders,
+    Dashboards,
+    Permissions,
+    Teams,
+    LibraryPanels,
+    Annotations,
+}
+
+impl MigrateResource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MigrateResource::Folders => "folders",
+            MigrateResource::Dashboards => "dashboards",
+            MigrateResource::Permissions => "permissions",
+            MigrateResource::Teams => "teams",
+            MigrateResource::LibraryPanels => "library-panels",
+            MigrateResource::Annotations => "annotations",
+        }
+    }
+}
+
+/// What happens to a resource which already exists on the target instance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ConflictPolicy {
+    /// Keep the existing resource and use it in place of the source one
+    Skip,
+    /// Replace the existing resource with the source one
+    Overwrite,
+    /// Add the source resource next to the existing one with a new uid and a suffixed title
+    Rename,
+}
+
+/// The available options to copy the resources of one grafana instance to another one
+#[derive(Debug, Args, Clone)]
+pub struct MigrateOptions {
+    /// The profile of the grafana instance the resources are copied from (only its url, auth and org_id are used)
+    #[arg(long)]
+    pub from: String,
+    /// The profile of the grafana instance the resources are copied to (only its url, auth and org_id are used)
+    #[arg(long)]
+    pub to: String,
+    /// Use it to copy only these kinds of resources [default: all]
+    #[arg(short, long, value_enum, value_delimiter = ',')]
+    pub resources: Vec<MigrateResource>,
+    /// Use it to decide what happens to resources which already exist on the target
+    #[arg(short, long, value_enum, default_value_t = ConflictPolicy::Skip)]
+    pub conflict: ConflictPolicy,
+    /// Use it to let the target generate new uids instead of keeping the ones of the source
+    #[arg(long, default_value_t = false)]
+    pub new_uids: bool,
+}
+
+impl MigrateOptions {
+    pub fn includes(&self, resource: MigrateResource) -> bool {
+        self.resources.is_empty() || self.resources.contains(&resource)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::api::folder::{FolderPermissionItem, SetFolderPermissionRequest};
+use crate::api::search::{SearchDashTypeRequest, DASH_DB_TYPE, DASH_FOLDER_TYPE};
+use crate::cli::folder::permission::options::PermissionTarget;
+use crate::cli::migrate::options::MigrateResource;
+use crate::cli::migrate::report::{sent, MigrationItem, MigrationResult};
+use crate::cli::migrate::run::Migration;
+use crate::error::GrafanaCliError;
+
+impl Migration<'_> {
+    /// Copies the own access control lists of the migrated folders and dashboards with the teams and users mapped onto the target
+    pub async fn migrate_permissions(&mut self) -> Result<(), GrafanaCliError> {
+        // without migrating them the folders and dashboards are expected with the same uid on the target
+        for (resource, r#type) in [(MigrateResource::Folders, DASH_FOLDER_TYPE), (MigrateResource::Dashboards, DASH_DB_TYPE)] {
+            if self.opt.includes(resource) {
+                continue;
+            }
+            let request = SearchDashTypeRequest { r#type: Some(r#type.to_string()), ..Default::default() };
+            for uid in self.source.search().dash_type_uids(&request).await? {
+                let target = match resource {
+                    MigrateResource::Folders => PermissionTarget::Folder(uid),
+                    _ => PermissionTarget::Dashboard(uid),
+                };
+                self.permission_targets.push((target.clone(), target));
+            }
+        }
+        for (source, target) in std::mem::take(&mut self.permission_targets) {
+            let item = match self.migrate_permission(&source, &target).await {
+                Ok(item) => item,
+                Err(error) => MigrationItem::failed(MigrateResource::Permissions, source.to_string(), &error),
+            };
+            self.push(item);
+        }
+        Ok(())
+    }
+
+    async fn migrate_permission(&mut self, source: &PermissionTarget, target: &PermissionTarget) -> Result<MigrationItem, GrafanaCliError> {
+        let mut items = Vec::new();
+        let mut unmapped = Vec::new();
+        for permission in source.permissions(self.source).await?.iter().filter(|permission| !permission.inherited) {
+            let item = permission.item();
+            if item.role.is_some() {
+                items.push(item);
+            } else if let Some(team_id) = item.team_id {
+                match self.team_ids.get(&team_id) {
+                    Some(target_team_id) => items.push(FolderPermissionItem::team(*target_team_id, item.permission)),
+                    None => unmapped.push(format!("team {}", permission.team)),
+                }
+            } else if item.user_id.is_some() {
+                match self.target_user_id(&permission.user_login).await? {
+                    Some(target_user_id) => items.push(FolderPermissionItem::user(target_user_id, item.permission)),
+                    None => unmapped.push(format!("user {}", permission.user_login)),
+                }
+            }
+        }
+        let count = items.len();
+        sent(target.set_permissions(self.target, &SetFolderPermissionRequest { items }).await)?;
+        let mut detail = format!("{} entries", count);
+        if !unmapped.is_empty() {
+            detail.push_str(&format!(", missing on the target: {}", unmapped.join(",")));
+        }
+        Ok(MigrationItem::new(MigrateResource::Permissions, source.to_string(), target.to_string(), MigrationResult::Updated, detail))
+    }
+}
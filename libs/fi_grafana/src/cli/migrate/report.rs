@@ -0,0 +1,83 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::cli::migrate::options::MigrateResource;
+use crate::cli::shell::output::Tabular;
+use crate::error::GrafanaCliError;
+
+/// The suffix of the titles of resources added next to an existing one by the rename conflict policy
+pub const RENAME_SUFFIX: &str = " (migrated)";
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MigrationResult {
+    Created,
+    Updated,
+    Renamed,
+    Skipped,
+    Failed,
+}
+
+impl MigrationResult {
+    pub const ALL: [MigrationResult; 5] = [
+        MigrationResult::Created,
+        MigrationResult::Updated,
+        MigrationResult::Renamed,
+        MigrationResult::Skipped,
+        MigrationResult::Failed,
+    ];
+}
+
+impl fmt::Display for MigrationResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationResult::Created => write!(f, "created"),
+            MigrationResult::Updated => write!(f, "updated"),
+            MigrationResult::Renamed => write!(f, "renamed"),
+            MigrationResult::Skipped => write!(f, "skipped"),
+            MigrationResult::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+/// The outcome of copying one resource
+#[derive(Debug, Serialize)]
+pub struct MigrationItem {
+    pub resource: MigrateResource,
+    /// The uid, name or id of the resource on the source instance
+    pub source: String,
+    /// The uid, name or id of the resource on the target instance
+    pub target: String,
+    pub result: MigrationResult,
+    pub detail: String,
+}
+
+impl MigrationItem {
+    pub fn new(resource: MigrateResource, source: impl Into<String>, target: impl Into<String>, result: MigrationResult, detail: impl Into<String>) -> Self {
+        Self { resource, source: source.into(), target: target.into(), result, detail: detail.into() }
+    }
+
+    pub fn failed(resource: MigrateResource, source: impl Into<String>, error: &GrafanaCliError) -> Self {
+        Self::new(resource, source, "", MigrationResult::Failed, error.to_string())
+    }
+}
+
+impl Tabular for MigrationItem {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["RESOURCE", "SOURCE", "TARGET", "RESULT", "DETAIL"]
+    }
+
+    fn row(&self, _wide: bool) -> Vec<String> {
+        vec![self.resource.as_str().to_string(), self.source.clone(), self.target.clone(), self.result.to_string(), self.detail.clone()]
+    }
+}
+
+/// A dry run records a writing request without a response, the migration goes on as if the request succeeded
+pub fn sent<T>(result: Result<T, GrafanaCliError>) -> Result<Option<T>, GrafanaCliError> {
+    match result {
+        Ok(response) => Ok(Some(response)),
+        Err(GrafanaCliError::DryRunResponseRequired(_)) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use crate::api::grafana::GrafanaClient;
+use crate::cli::folder::permission::options::PermissionTarget;
+use crate::cli::migrate::options::{MigrateOptions, MigrateResource};
+use crate::cli::migrate::report::{MigrationItem, MigrationResult};
+use crate::cli::shell::client::ClientOptions;
+use crate::cli::shell::output::OutputFormat;
+use crate::error::GrafanaCliError;
+
+/// The state of a migration, the uids and ids of the copied resources are mapped from the source onto the target
+pub struct Migration<'a> {
+    pub source: &'a GrafanaClient,
+    pub target: &'a GrafanaClient,
+    pub opt: &'a MigrateOptions,
+    pub folder_uids: HashMap<String, String>,
+    pub dashboard_uids: HashMap<String, String>,
+    pub library_panel_uids: HashMap<String, String>,
+    pub team_ids: HashMap<u32, u32>,
+    /// The target user ids by login, none if the user does not exist on the target
+    pub user_ids: HashMap<String, Option<u32>>,
+    /// The folders and dashboards (source and target) whose access control lists are copied
+    pub permission_targets: Vec<(PermissionTarget, PermissionTarget)>,
+    pub report: Vec<MigrationItem>,
+}
+
+impl<'a> Migration<'a> {
+    pub fn new(source: &'a GrafanaClient, target: &'a GrafanaClient, opt: &'a MigrateOptions) -> Self {
+        Self {
+            source,
+            target,
+            opt,
+            folder_uids: HashMap::new(),
+            dashboard_uids: HashMap::new(),
+            library_panel_uids: HashMap::new(),
+            team_ids: HashMap::new(),
+            user_ids: HashMap::new(),
+            permission_targets: Vec::new(),
+            report: Vec::new(),
+        }
+    }
+
+    /// The target folder of a source folder uid, folders which are not migrated keep their uid
+    pub fn target_folder_uid(&self, source_uid: &str) -> Option<String> {
+        if source_uid.is_empty() {
+            return None;
+        }
+        Some(self.folder_uids.get(source_uid).cloned().unwrap_or(source_uid.to_string()))
+    }
+
+    /// The target dashboard of a source dashboard uid, dashboards which are not migrated keep their uid
+    pub fn target_dashboard_uid(&self, source_uid: &str) -> Option<String> {
+        if self.opt.includes(MigrateResource::Dashboards) {
+            return self.dashboard_uids.get(source_uid).cloned();
+        }
+        Some(source_uid.to_string())
+    }
+
+    /// The uid the target gets for a new resource, none lets grafana generate one
+    pub fn new_uid(&self, source_uid: &str) -> Option<String> {
+        (!self.opt.new_uids).then(|| source_uid.to_string())
+    }
+
+    /// The id of the user with the login on the target, looked up once per login
+    pub async fn target_user_id(&mut self, login: &str) -> Result<Option<u32>, GrafanaCliError> {
+        if let Some(user_id) = self.user_ids.get(login) {
+            return Ok(*user_id);
+        }
+        let user_id = match self.target.users().lookup(login).await {
+            Ok(user) => Some(user.id),
+            Err(GrafanaCliError::NotFound(_)) => None,
+            Err(error) => return Err(error),
+        };
+        self.user_ids.insert(login.to_string(), user_id);
+        Ok(user_id)
+    }
+
+    pub fn push(&mut self, item: MigrationItem) {
+        if item.result == MigrationResult::Failed {
+            eprintln!("Failed to migrate the {} {}: {}", item.resource.as_str(), item.source, item.detail);
+        }
+        self.report.push(item);
+    }
+}
+
+/// Copies the resources of the --from instance to the --to instance, the resources others depend on come first
+pub async fn handle_migrate(client_options: &ClientOptions, target: &GrafanaClient, opt: &MigrateOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let source = client_options.grafana_client_from_profile_only(&opt.from)?.with_retry_listener(target.on_retry.clone());
+    if source.api == target.api && source.org_id == target.org_id {
+        return Err(GrafanaCliError::CanNotMigrateToTheSameInstance(target.api.clone()));
+    }
+    let mut migration = Migration::new(&source, target, opt);
+    if opt.includes(MigrateResource::Teams) {
+        migration.migrate_teams().await?;
+    } else if opt.includes(MigrateResource::Permissions) {
+        migration.map_teams().await?;
+    }
+    if opt.includes(MigrateResource::Folders) {
+        migration.migrate_folders().await?;
+    }
+    if opt.includes(MigrateResource::LibraryPanels) {
+        migration.migrate_library_panels().await?;
+    }
+    if opt.includes(MigrateResource::Dashboards) {
+        migration.migrate_dashboards().await?;
+    }
+    if opt.includes(MigrateResource::Permissions) {
+        migration.migrate_permissions().await?;
+    }
+    if opt.includes(MigrateResource::Annotations) {
+        migration.migrate_annotations().await?;
+    }
+    output.print(&migration.report)?;
+    let counts: Vec<String> = MigrationResult::ALL
+        .iter()
+        .map(|result| format!("{} {}", migration.report.iter().filter(|item| item.result == *result).count(), result))
+        .collect();
+    output.message(format!("Migrated {} resources from {} to {}: {}", migration.report.len(), opt.from, opt.to, counts.join(", ")));
+    let failed = migration.report.iter().filter(|item| item.result == MigrationResult::Failed).count();
+    if failed > 0 {
+        return Err(GrafanaCliError::SomeMigrationsFailed(failed, migration.report.len()));
+    }
+    Ok(())
+}
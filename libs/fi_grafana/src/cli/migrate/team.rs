@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use crate::api::team::{AddTeamRequest, GetTeamResponse, UpdateTeamRequest};
+use crate::cli::migrate::options::{ConflictPolicy, MigrateResource};
+use crate::cli::migrate::report::{sent, MigrationItem, MigrationResult, RENAME_SUFFIX};
+use crate::cli::migrate::run::Migration;
+use crate::error::GrafanaCliError;
+
+impl Migration<'_> {
+    /// Maps the source teams onto the target teams with the same name without changing anything
+    pub async fn map_teams(&mut self) -> Result<(), GrafanaCliError> {
+        let target_teams = self.target_teams_by_name().await?;
+        for team in self.source.teams().search_all(None).await? {
+            if let Some(target_team) = target_teams.get(&team.name) {
+                self.team_ids.insert(team.id, target_team.id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies the teams matched by name with their members matched by login or email
+    pub async fn migrate_teams(&mut self) -> Result<(), GrafanaCliError> {
+        let target_teams = self.target_teams_by_name().await?;
+        for team in self.source.teams().search_all(None).await? {
+            let item = match self.migrate_team(&team, target_teams.get(&team.name)).await {
+                Ok(item) => item,
+                Err(error) => MigrationItem::failed(MigrateResource::Teams, &team.name, &error),
+            };
+            self.push(item);
+        }
+        Ok(())
+    }
+
+    async fn target_teams_by_name(&self) -> Result<HashMap<String, GetTeamResponse>, GrafanaCliError> {
+        Ok(self.target.teams().search_all(None).await?.into_iter().map(|team| (team.name.clone(), team)).collect())
+    }
+
+    async fn migrate_team(&mut self, team: &GetTeamResponse, existing: Option<&GetTeamResponse>) -> Result<MigrationItem, GrafanaCliError> {
+        let (name, team_id, result) = match existing {
+            Some(existing) if self.opt.conflict == ConflictPolicy::Skip => {
+                self.team_ids.insert(team.id, existing.id);
+                return Ok(MigrationItem::new(MigrateResource::Teams, &team.name, &existing.name, MigrationResult::Skipped, "the team exists"));
+            }
+            Some(existing) if self.opt.conflict == ConflictPolicy::Overwrite => {
+                let request = UpdateTeamRequest { name: team.name.clone(), email: team.email.clone() };
+                sent(self.target.teams().update(existing.id, &request).await)?;
+                (team.name.clone(), Some(existing.id), MigrationResult::Updated)
+            }
+            _ => {
+                let (name, result) = match existing {
+                    Some(_) => (format!("{}{}", team.name, RENAME_SUFFIX), MigrationResult::Renamed),
+                    None => (team.name.clone(), MigrationResult::Created),
+                };
+                let request = AddTeamRequest { name: name.clone(), email: Some(team.email.clone()).filter(|email| !email.is_empty()), org_id: None };
+                let team_id = match sent(self.target.teams().create(&request).await)? {
+                    // the placeholder response of a dry run has no team id
+                    Some(_) if self.target.dry_run.is_some() => None,
+                    Some(response) => Some(response.team_id.ok_or(GrafanaCliError::NoTeamIdReceivedFromGrafanaOnTeamCreation)?),
+                    None => None,
+                };
+                (name, team_id, result)
+            }
+        };
+        let Some(team_id) = team_id else {
+            return Ok(MigrationItem::new(MigrateResource::Teams, &team.name, name, result, "dry run, members not matched"));
+        };
+        self.team_ids.insert(team.id, team_id);
+        let detail = self.migrate_team_members(team.id, team_id).await?;
+        Ok(MigrationItem::new(MigrateResource::Teams, &team.name, name, result, detail))
+    }
+
+    /// Adds the members missing on the target team, the users are matched by login and then by email
+    async fn migrate_team_members(&mut self, source_team_id: u32, target_team_id: u32) -> Result<String, GrafanaCliError> {
+        let members = self.source.teams().members(source_team_id).await?;
+        let existing: Vec<u32> = self.target.teams().members(target_team_id).await?.iter().map(|member| member.user_id).collect();
+        let mut missing = Vec::new();
+        let mut added = 0;
+        for member in &members {
+            let user_id = match self.target_user_id(&member.login).await? {
+                Some(user_id) => Some(user_id),
+                None if !member.email.is_empty() => self.target_user_id(&member.email).await?,
+                None => None,
+            };
+            match user_id {
+                Some(user_id) if existing.contains(&user_id) => {}
+                Some(user_id) => {
+                    sent(self.target.teams().add_member(target_team_id, user_id).await)?;
+                    added += 1;
+                }
+                None => missing.push(member.login.clone()),
+            }
+        }
+        let mut detail = format!("{} of {} members added", added, members.len());
+        if !missing.is_empty() {
+            detail.push_str(&format!(", users missing on the target: {}", missing.join(",")));
+        }
+        Ok(detail)
+    }
+}
@@ -5,4 +5,6 @@ pub mod service_account;
 pub mod shell;
 pub mod team;
 pub mod user;
-mod dashboard;
+pub mod dashboard;
+pub mod migrate;
+pub mod search;
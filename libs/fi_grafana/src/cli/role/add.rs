@@ -0,0 +1,22 @@
+use crate::api::grafana::GrafanaClient;
+use crate::cli::role::options::RoleOptions;
+use crate::cli::shell::output::OutputFormat;
+use crate::error::GrafanaCliError;
+
+pub async fn handle_add_role(grafana_client: &GrafanaClient, opt: &RoleOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    // a role created from the file is assigned by the uid grafana responds with
+    let mut uid = opt.uid.clone();
+    if let Some(role) = opt.role_definition()? {
+        let response = grafana_client.roles().create(&role).await?;
+        output.print_one(&response)?;
+        uid = Some(response.uid);
+    }
+    if let Some(assignee) = opt.assignee(grafana_client).await? {
+        let uid = uid.as_deref().ok_or(GrafanaCliError::CanNotAssignRoleWithoutUid)?;
+        let response = grafana_client.roles().assign(assignee, uid).await?;
+        output.message(format!("{} [role: {}, assignee: {}]", response.message, uid, assignee));
+    } else if opt.file.is_none() {
+        output.message("Nothing to add, use --file to add a custom role or --uid with --user, --team-id or --service-account-id to assign one");
+    }
+    Ok(())
+}
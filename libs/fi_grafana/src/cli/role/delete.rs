@@ -0,0 +1,22 @@
+use crate::api::grafana::GrafanaClient;
+use crate::cli::role::options::RoleOptions;
+use crate::cli::shell::input::{confirm, prompt_option};
+use crate::cli::shell::output::OutputFormat;
+use crate::error::GrafanaCliError;
+
+pub async fn handle_del_role(grafana_client: &GrafanaClient, opt: &RoleOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let uid = prompt_option("Enter the role uid: ", &opt.uid).ok_or(GrafanaCliError::CanNotDeleteRoleWithoutUid)?;
+    if let Some(assignee) = opt.assignee(grafana_client).await? {
+        let response = grafana_client.roles().unassign(assignee, &uid).await?;
+        output.message(format!("{} [role: {}, assignee: {}]", response.message, uid, assignee));
+        return Ok(());
+    }
+    let role = grafana_client.roles().get(&uid).await?;
+    if confirm(&format!("Delete role [{}] with [{}] permissions?", role.name, role.permissions.len()), opt.yes) {
+        let response = grafana_client.roles().delete(&uid, opt.force).await?;
+        output.message(format!("{} [uid: {}, name: {}]", response.message, uid, role.name));
+    } else {
+        output.message("No delete confirmation. Skipping request..");
+    }
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::api::grafana::GrafanaClient;
+use crate::api::role::{GetRoleResponse, RoleAssignee, RolePermission};
+use crate::cli::role::options::RoleOptions;
+use crate::cli::shell::output::{cell, OutputFormat, Tabular};
+use crate::error::GrafanaCliError;
+
+impl Tabular for GetRoleResponse {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["UID", "NAME", "DISPLAY_NAME", "GROUP", "VERSION"];
+        if wide {
+            headers.extend(["GLOBAL", "PERMISSIONS", "DESCRIPTION"]);
+        }
+        headers
+    }
+
+    fn row(&self, wide: bool) -> Vec<String> {
+        let mut row = vec![self.uid.clone(), self.name.clone(), cell(&self.display_name), cell(&self.group), self.version.to_string()];
+        if wide {
+            row.extend([self.global.to_string(), self.permissions.len().to_string(), cell(&self.description)]);
+        }
+        row
+    }
+}
+
+impl Tabular for RolePermission {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["ACTION", "SCOPE"]
+    }
+
+    fn row(&self, _wide: bool) -> Vec<String> {
+        vec![self.action.clone(), cell(&self.scope)]
+    }
+}
+
+pub async fn handle_get_role(grafana_client: &GrafanaClient, opt: &RoleOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    if let Some(uid) = &opt.uid {
+        let role = grafana_client.roles().get(uid).await?;
+        output.print_one(&role)?;
+        if output.is_human() {
+            output.message(format!("Permissions ({}):", role.permissions.len()));
+            output.print(&role.permissions)?;
+        }
+        return Ok(());
+    }
+    if let Some(assignee) = opt.assignee(grafana_client).await? {
+        if let (true, RoleAssignee::User(user_id)) = (opt.permissions, assignee) {
+            let permissions = grafana_client.roles().user_permissions(user_id).await?;
+            output.message(format!("Effective permissions ({}):", permissions.len()));
+            return output.print(&permissions);
+        }
+        let roles = grafana_client.roles().assigned(assignee).await?;
+        output.message(format!("Assigned roles ({}):", roles.len()));
+        return output.print(&roles);
+    }
+    let roles: Vec<GetRoleResponse> = grafana_client.roles().list().await?
+        .into_iter()
+        .filter(|role| !opt.custom || !role.is_builtin())
+        .filter(|role| !opt.builtin || role.is_builtin())
+        .collect();
+    output.message(format!("Roles ({}):", roles.len()));
+    output.print(&roles)
+}
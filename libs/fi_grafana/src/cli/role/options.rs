@@ -0,0 +1,64 @@
+use std::fs;
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::api::grafana::GrafanaClient;
+use crate::api::role::{RoleAssignee, RoleDefinition};
+use crate::error::GrafanaCliError;
+
+/// The available options for role requests
+#[derive(Debug, Args, Clone)]
+pub struct RoleOptions {
+    /// Use it to get, set, delete, assign or unassign the role with this uid
+    #[arg(short, long)]
+    pub uid: Option<String>,
+    /// Use it to add or set a custom role defined in this yaml file (name, displayName, description, group, permissions)
+    #[arg(short, long)]
+    pub file: Option<PathBuf>,
+    /// Use it to get only the custom roles
+    #[arg(long, default_value_t = false, conflicts_with = "builtin")]
+    pub custom: bool,
+    /// Use it to get only the built-in (fixed, basic and managed) roles
+    #[arg(long, default_value_t = false)]
+    pub builtin: bool,
+    /// Use it to get, assign or unassign the roles of this user (id, login or email)
+    #[arg(long, conflicts_with_all = ["team_id", "service_account_id"])]
+    pub user: Option<String>,
+    /// Use it to get, assign or unassign the roles of the team with this id
+    #[arg(long, conflicts_with = "service_account_id")]
+    pub team_id: Option<u32>,
+    /// Use it to get, assign or unassign the roles of the service account with this id
+    #[arg(long)]
+    pub service_account_id: Option<u32>,
+    /// Use it to get the effective permissions of the user given by --user
+    #[arg(long, default_value_t = false, requires = "user")]
+    pub permissions: bool,
+    /// Use it to delete a role that is still assigned
+    #[arg(long, default_value_t = false)]
+    pub force: bool,
+    /// Use it to confirm the deletion of the role upfront
+    #[arg(short, long, default_value_t = false)]
+    pub yes: bool,
+}
+
+impl RoleOptions {
+    /// Reads the custom role definition given by --file
+    pub fn role_definition(&self) -> Result<Option<RoleDefinition>, GrafanaCliError> {
+        let Some(file) = &self.file else {
+            return Ok(None);
+        };
+        let content = fs::read_to_string(file)?;
+        serde_yaml::from_str(&content)
+            .map(Some)
+            .map_err(|error| GrafanaCliError::InvalidRoleFile(file.display().to_string(), error.to_string()))
+    }
+
+    /// Resolves the user, team or service account the role is assigned to
+    pub async fn assignee(&self, grafana_client: &GrafanaClient) -> Result<Option<RoleAssignee>, GrafanaCliError> {
+        if let Some(user) = &self.user {
+            return Ok(Some(RoleAssignee::User(grafana_client.users().resolve_id(user).await?)));
+        }
+        Ok(self.team_id.map(RoleAssignee::Team).or(self.service_account_id.map(RoleAssignee::ServiceAccount)))
+    }
+}
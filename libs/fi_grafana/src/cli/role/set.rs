@@ -0,0 +1,19 @@
+use crate::api::grafana::GrafanaClient;
+use crate::api::role::RoleDefinition;
+use crate::cli::role::options::RoleOptions;
+use crate::cli::shell::output::OutputFormat;
+use crate::error::GrafanaCliError;
+
+pub async fn handle_set_role(grafana_client: &GrafanaClient, opt: &RoleOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let role = opt.role_definition()?.ok_or(GrafanaCliError::CanNotSetRoleWithoutFile)?;
+    let uid = opt.uid.clone().or(role.uid.clone()).ok_or(GrafanaCliError::CanNotSetRoleWithoutUid)?;
+    // grafana only accepts an update with a version greater than the current one
+    let current = grafana_client.roles().get(&uid).await?;
+    let role = RoleDefinition {
+        uid: Some(uid.clone()),
+        version: Some(role.version.unwrap_or(current.version + 1)),
+        ..role
+    };
+    let response = grafana_client.roles().update(&uid, &role).await?;
+    output.print_one(&response)
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::api::grafana::GrafanaClient;
+use crate::api::search::{DashTypeResponse, SearchDashTypeRequest, DASH_FOLDER_TYPE};
+use crate::cli::folder::get::{get_folder_tree, FolderNode};
+use crate::cli::search::options::SearchOptions;
+use crate::cli::shell::output::{cell, OutputFormat, Tabular};
+use crate::error::GrafanaCliError;
+
+/// A dashboard or folder found by the search with the path of the folder it is nested in
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+    /// The titles of the folders from the root down to the containing one, none at the root level
+    pub folder_path: Option<String>,
+    #[serde(flatten)]
+    pub item: DashTypeResponse,
+}
+
+impl Tabular for SearchResult {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["FOLDER", "TITLE", "UID", "TAGS", "URL"];
+        if wide {
+            headers.extend(["TYPE", "FOLDER_UID", "STARRED"]);
+        }
+        headers
+    }
+
+    fn row(&self, wide: bool) -> Vec<String> {
+        let mut row = vec![
+            cell(&self.folder_path),
+            self.item.title.clone(),
+            self.item.uid.clone(),
+            self.item.tags.join(","),
+            self.item.url.clone(),
+        ];
+        if wide {
+            row.extend([self.item.r#type.clone(), cell(&self.item.folder_uid), self.item.is_starred.to_string()]);
+        }
+        row
+    }
+}
+
+/// Searches the dashboards and folders, a single page with limit or page and all matches otherwise
+pub async fn handle_search(grafana_client: &GrafanaClient, opt: &SearchOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let folder_uids = resolve_folder_uids(grafana_client, &opt.folder).await?;
+    let request = SearchDashTypeRequest {
+        r#type: opt.search_type.map(|search_type| search_type.as_str().to_string()),
+        query: opt.query.clone(),
+        folder_uids: (!folder_uids.is_empty()).then_some(folder_uids),
+        tag: (!opt.tag.is_empty()).then(|| opt.tag.clone()),
+        starred: opt.starred.then_some(true),
+        sort: opt.sort.map(|sort| sort.as_str().to_string()),
+        deleted: opt.deleted.then_some(true),
+        limit: opt.limit,
+        page: opt.page,
+        ..Default::default()
+    };
+    let items = if opt.limit.is_some() || opt.page.is_some() {
+        grafana_client.search().dash_types(&request).await?
+    } else {
+        grafana_client.search().dash_types_all(&request).await?
+    };
+    if opt.uids {
+        // plain lines regardless of the output format to pipe them into other commands
+        for item in &items {
+            println!("{}", item.uid);
+        }
+        return Ok(());
+    }
+    let paths = folder_paths(grafana_client, &items).await?;
+    let results: Vec<SearchResult> = items
+        .into_iter()
+        .map(|item| {
+            let folder_path = item.folder_uid.as_ref().filter(|folder_uid| !folder_uid.is_empty()).map(|folder_uid| {
+                paths.get(folder_uid).cloned().or_else(|| item.folder_title.clone()).unwrap_or_else(|| folder_uid.clone())
+            });
+            SearchResult { folder_path, item }
+        })
+        .collect();
+    output.print(&results)
+}
+
+/// Resolves every folder given by uid or by its exact title, a title shared by several folders matches all of them
+async fn resolve_folder_uids(grafana_client: &GrafanaClient, folders: &[String]) -> Result<Vec<String>, GrafanaCliError> {
+    let mut folder_uids = Vec::new();
+    for folder in folders {
+        match grafana_client.folders().get(folder).await {
+            Ok(response) => folder_uids.push(response.uid),
+            Err(GrafanaCliError::NotFound(_)) => {
+                let request = SearchDashTypeRequest::type_query(DASH_FOLDER_TYPE.to_string(), folder.clone());
+                let matches: Vec<String> = grafana_client.search().dash_types_all(&request).await?
+                    .into_iter()
+                    .filter(|response| response.title == *folder)
+                    .map(|response| response.uid)
+                    .collect();
+                if matches.is_empty() {
+                    return Err(GrafanaCliError::FolderNotFound(folder.clone()));
+                }
+                folder_uids.extend(matches);
+            }
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(folder_uids)
+}
+
+/// The paths of all folders by uid, only fetched when a result is nested in a folder
+async fn folder_paths(grafana_client: &GrafanaClient, items: &[DashTypeResponse]) -> Result<HashMap<String, String>, GrafanaCliError> {
+    let mut paths = HashMap::new();
+    if items.iter().any(|item| item.folder_uid.as_ref().is_some_and(|folder_uid| !folder_uid.is_empty())) {
+        collect_paths(&get_folder_tree(grafana_client, None).await?, "", &mut paths);
+    }
+    Ok(paths)
+}
+
+fn collect_paths(nodes: &[FolderNode], prefix: &str, paths: &mut HashMap<String, String>) {
+    for node in nodes {
+        let path = format!("{}{}", prefix, node.title);
+        collect_paths(&node.children, &format!("{}/", path), paths);
+        paths.insert(node.uid.clone(), path);
+    }
+}
@@ -0,0 +1,70 @@
+use clap::{Args, ValueEnum};
+
+use crate::api::search::{DASH_DB_TYPE, DASH_FOLDER_TYPE};
+
+/// The kinds of results a search is narrowed to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SearchType {
+    Dashboard,
+    Folder,
+}
+
+impl SearchType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchType::Dashboard => DASH_DB_TYPE,
+            SearchType::Folder => DASH_FOLDER_TYPE,
+        }
+    }
+}
+
+/// The orders grafana sorts the results by instead of their relevance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SearchSort {
+    AlphaAsc,
+    AlphaDesc,
+}
+
+impl SearchSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchSort::AlphaAsc => "alpha-asc",
+            SearchSort::AlphaDesc => "alpha-desc",
+        }
+    }
+}
+
+/// The available options to search the dashboards and folders
+#[derive(Debug, Args, Clone)]
+pub struct SearchOptions {
+    /// Use it to find the dashboards and folders whose title matches the query
+    #[arg(short, long)]
+    pub query: Option<String>,
+    /// Use it to find only the dashboards carrying all of these tags (repeat it for several tags)
+    #[arg(short, long)]
+    pub tag: Vec<String>,
+    /// Use it to find only the dashboards starred by the current user
+    #[arg(long, default_value_t = false)]
+    pub starred: bool,
+    /// Use it to search only within these folders, given by uid or title (repeat it for several folders)
+    #[arg(short, long)]
+    pub folder: Vec<String>,
+    /// Use it to find only dashboards or only folders
+    #[arg(long = "type", value_enum)]
+    pub search_type: Option<SearchType>,
+    /// Use it to sort the results alphabetically instead of by relevance
+    #[arg(short, long, value_enum)]
+    pub sort: Option<SearchSort>,
+    /// Use it to fetch a single page of this size instead of all results
+    #[arg(short, long)]
+    pub limit: Option<u32>,
+    /// Use it to fetch this 1-based page of the results
+    #[arg(short, long)]
+    pub page: Option<u32>,
+    /// Use it to find the recently deleted dashboards which can still be restored
+    #[arg(long, default_value_t = false)]
+    pub deleted: bool,
+    /// Use it to print only the uids, one per line, as input for other commands
+    #[arg(long, default_value_t = false)]
+    pub uids: bool,
+}
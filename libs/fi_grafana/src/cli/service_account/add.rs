@@ -0,0 +1,46 @@
+use crate::api::grafana::GrafanaClient;
+use crate::api::service_account::{AddServiceAccountRequest, AddTokenRequest, AddTokenResponse};
+use crate::cli::service_account::options::ServiceAccountOptions;
+use crate::cli::shell::input::prompt_option;
+use crate::cli::shell::output::{OutputFormat, Tabular};
+use crate::error::GrafanaCliError;
+
+impl Tabular for AddTokenResponse {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["ID", "NAME", "KEY"]
+    }
+
+    fn row(&self, _wide: bool) -> Vec<String> {
+        vec![self.id.to_string(), self.name.clone(), self.key.clone()]
+    }
+}
+
+pub async fn handle_add_service_account(grafana_client: &GrafanaClient, opt: &ServiceAccountOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    if let Some(token_name) = &opt.token_name {
+        let id = opt.id.ok_or(GrafanaCliError::CanNotManageTokensWithoutServiceAccountId)?;
+        let token = add_token(grafana_client, id, token_name.clone(), opt).await?;
+        return print_token_once(&token, output);
+    }
+    let name = prompt_option("Enter a service account name: ", &opt.name).ok_or(GrafanaCliError::CanNotAddServiceAccountWithoutName)?;
+    let request = AddServiceAccountRequest {
+        name,
+        role: opt.role,
+        is_disabled: opt.disable,
+    };
+    let response = grafana_client.service_accounts().create(&request).await?;
+    output.print_one(&response)
+}
+
+pub async fn add_token(grafana_client: &GrafanaClient, id: u32, name: String, opt: &ServiceAccountOptions) -> Result<AddTokenResponse, GrafanaCliError> {
+    let request = AddTokenRequest {
+        name,
+        seconds_to_live: opt.seconds_to_live(),
+    };
+    grafana_client.service_accounts().create_token(id, &request).await
+}
+
+/// Grafana never shows the key of a token again, so it is printed right after creation
+pub fn print_token_once(token: &AddTokenResponse, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    output.message("Store the key now, it can not be shown again:");
+    output.print_one(token)
+}
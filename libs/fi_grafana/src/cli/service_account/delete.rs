@@ -0,0 +1,26 @@
+use crate::api::grafana::GrafanaClient;
+use crate::cli::service_account::options::ServiceAccountOptions;
+use crate::cli::shell::input::{confirm, prompt_option};
+use crate::cli::shell::output::OutputFormat;
+use crate::error::GrafanaCliError;
+
+pub async fn handle_del_service_account(grafana_client: &GrafanaClient, opt: &ServiceAccountOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let id = prompt_option("Enter the service account id: ", &opt.id).ok_or(GrafanaCliError::CanNotDeleteServiceAccountWithoutId)?;
+    let service_account = grafana_client.service_accounts().get(id).await?;
+    if let Some(token_id) = opt.token_id {
+        if confirm(&format!("Revoke token [{}] of service account [{}]?", token_id, service_account.name), opt.yes) {
+            let response = grafana_client.service_accounts().delete_token(id, token_id).await?;
+            output.message(format!("{} [id: {}, token id: {}]", response.message, id, token_id));
+        } else {
+            output.message("No delete confirmation. Skipping request..");
+        }
+        return Ok(());
+    }
+    if confirm(&format!("Delete service account [{}] with [{}] tokens?", service_account.name, service_account.tokens), opt.yes) {
+        let response = grafana_client.service_accounts().delete(id).await?;
+        output.message(format!("{} [id: {}, name: {}]", response.message, id, service_account.name));
+    } else {
+        output.message("No delete confirmation. Skipping request..");
+    }
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use crate::api::grafana::GrafanaClient;
+use crate::api::page::DEFAULT_PAGE_SIZE;
+use crate::api::service_account::{GetServiceAccountResponse, GetTokenResponse};
+use crate::cli::service_account::options::ServiceAccountOptions;
+use crate::cli::shell::date::from_rfc3339_to_local_datetime;
+use crate::cli::shell::output::{OutputFormat, Tabular};
+use crate::error::GrafanaCliError;
+
+impl Tabular for GetServiceAccountResponse {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["ID", "NAME", "ROLE", "TOKENS", "DISABLED"];
+        if wide {
+            headers.extend(["LOGIN", "ORG_ID"]);
+        }
+        headers
+    }
+
+    fn row(&self, wide: bool) -> Vec<String> {
+        let mut row = vec![self.id.to_string(), self.name.clone(), self.role.clone(), self.tokens.to_string(), self.is_disabled.to_string()];
+        if wide {
+            row.extend([self.login.clone(), self.org_id.to_string()]);
+        }
+        row
+    }
+}
+
+impl Tabular for GetTokenResponse {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["ID", "NAME", "CREATED", "EXPIRES", "LAST_USED", "EXPIRED"];
+        if wide {
+            headers.push("REVOKED");
+        }
+        headers
+    }
+
+    fn row(&self, wide: bool) -> Vec<String> {
+        let datetime = |datetime: &Option<String>, none: &str| datetime.as_deref().map(from_rfc3339_to_local_datetime).unwrap_or(none.to_string());
+        let mut row = vec![
+            self.id.to_string(),
+            self.name.clone(),
+            datetime(&self.created, "-"),
+            datetime(&self.expiration, "never"),
+            datetime(&self.last_used_at, "never"),
+            self.has_expired.to_string(),
+        ];
+        if wide {
+            row.push(self.is_revoked.to_string());
+        }
+        row
+    }
+}
+
+pub async fn handle_get_service_account(grafana_client: &GrafanaClient, opt: &ServiceAccountOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    if let Some(id) = opt.id {
+        if opt.tokens {
+            let tokens = grafana_client.service_accounts().tokens(id).await?;
+            output.message(format!("Tokens ({}):", tokens.len()));
+            return output.print(&tokens);
+        }
+        let response = grafana_client.service_accounts().get(id).await?;
+        return output.print_one(&response);
+    }
+    let service_accounts = if opt.limit.is_some() || opt.page.is_some() {
+        let limit = opt.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        grafana_client.service_accounts().search_page(opt.query.as_deref(), opt.page.unwrap_or(1), limit).await?.service_accounts
+    } else {
+        grafana_client.service_accounts().search_all(opt.query.as_deref()).await?
+    };
+    output.message(format!("Service accounts ({}):", service_accounts.len()));
+    output.print(&service_accounts)
+}
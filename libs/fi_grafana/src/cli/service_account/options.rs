@@ -0,0 +1,60 @@
+use clap::Args;
+
+use crate::api::user::OrgRole;
+
+/// The available options for service account requests
+#[derive(Debug, Args, Clone)]
+pub struct ServiceAccountOptions {
+    /// Use it to get, set or delete a service account (or its tokens) by its id
+    #[arg(short, long)]
+    pub id: Option<u32>,
+    /// Use it to add a service account with this name or to rename a service account
+    #[arg(short, long)]
+    pub name: Option<String>,
+    /// Use it to get service accounts that match the given query name
+    #[arg(short, long)]
+    pub query: Option<String>,
+    /// Use it to limit the number of service accounts per page (otherwise all pages are fetched)
+    #[arg(short, long)]
+    pub limit: Option<u32>,
+    /// Use it to get the service accounts of only this page (otherwise all pages are fetched)
+    #[arg(short, long)]
+    pub page: Option<u32>,
+    /// Use it to add a service account with this role or to change the role of a service account
+    #[arg(short, long, value_enum)]
+    pub role: Option<OrgRole>,
+    /// Use it to disable a service account, the tokens of disabled service accounts are rejected
+    #[arg(long, default_value_t = false, conflicts_with = "enable")]
+    pub disable: bool,
+    /// Use it to enable a disabled service account
+    #[arg(long, default_value_t = false)]
+    pub enable: bool,
+    /// Use it to get the tokens of the service account given by --id
+    #[arg(long, default_value_t = false)]
+    pub tokens: bool,
+    /// Use it to add a token with this name to the service account given by --id
+    #[arg(long)]
+    pub token_name: Option<String>,
+    /// Use it to add a token that expires after this number of days (otherwise the token never expires)
+    #[arg(long)]
+    pub ttl_days: Option<u32>,
+    /// Use it to delete (revoke) the token with this id of the service account given by --id
+    #[arg(long)]
+    pub token_id: Option<u32>,
+    /// Use it to add a new token to the service account given by --id and delete its tokens older than --older-than-days
+    #[arg(long, default_value_t = false, requires = "older_than_days")]
+    pub rotate: bool,
+    /// Use it to delete the tokens created more than this number of days ago on rotation (0 deletes all but the new one)
+    #[arg(long, requires = "rotate")]
+    pub older_than_days: Option<u32>,
+    /// Use it to confirm the deletion of service accounts or tokens upfront
+    #[arg(short, long, default_value_t = false)]
+    pub yes: bool,
+}
+
+impl ServiceAccountOptions {
+    /// The time to live of a new token in seconds
+    pub fn seconds_to_live(&self) -> Option<u64> {
+        self.ttl_days.map(|days| u64::from(days) * 24 * 60 * 60)
+    }
+}
@@ -0,0 +1,86 @@
+use chrono::{Duration, Utc};
+
+use crate::api::grafana::{GrafanaAuth, GrafanaClient};
+use crate::api::service_account::{GetTokenResponse, UpdateServiceAccountRequest};
+use crate::cli::service_account::add::{add_token, print_token_once};
+use crate::cli::service_account::options::ServiceAccountOptions;
+use crate::cli::shell::date::from_rfc3339_to_utc_datetime;
+use crate::cli::shell::input::{confirm, prompt_option};
+use crate::cli::shell::output::OutputFormat;
+use crate::error::GrafanaCliError;
+
+pub async fn handle_set_service_account(grafana_client: &GrafanaClient, opt: &ServiceAccountOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let id = prompt_option("Enter the service account id: ", &opt.id).ok_or(GrafanaCliError::CanNotSetServiceAccountWithoutId)?;
+    let request = UpdateServiceAccountRequest {
+        name: opt.name.clone(),
+        role: opt.role,
+        is_disabled: (opt.disable || opt.enable).then_some(opt.disable),
+    };
+    let has_update = request.name.is_some() || request.role.is_some() || request.is_disabled.is_some();
+    if has_update {
+        let response = grafana_client.service_accounts().update(id, &request).await?;
+        output.message(format!("{} [id: {}]", response.message, id));
+    }
+    if opt.rotate {
+        rotate_tokens(grafana_client, id, opt, output).await?;
+    } else if !has_update {
+        output.message("Nothing to set, use --name, --role, --disable, --enable or --rotate");
+    }
+    Ok(())
+}
+
+/// Adds a new token and deletes the tokens created before the --older-than-days cutoff except the one the cli authenticates with
+async fn rotate_tokens(grafana_client: &GrafanaClient, id: u32, opt: &ServiceAccountOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let older_than_days = opt.older_than_days.ok_or(GrafanaCliError::CanNotRotateTokensWithoutAge)?;
+    let service_account = grafana_client.service_accounts().get(id).await?;
+    let tokens = grafana_client.service_accounts().tokens(id).await?;
+    let token_in_use = token_in_use(grafana_client, id, &tokens).await;
+    let now = Utc::now();
+    let token_name = opt.token_name.clone().unwrap_or(format!("{}-{}", service_account.name, now.format("%Y%m%d%H%M%S")));
+    let token = add_token(grafana_client, id, token_name, opt).await?;
+    print_token_once(&token, output)?;
+    if let Some(in_use) = &token_in_use {
+        output.message(format!("The cli authenticates as this service account, the most recently used token [{}] is kept as it is likely the one in use", in_use.name));
+    }
+    let cutoff = now - Duration::days(i64::from(older_than_days));
+    let old_tokens: Vec<_> = tokens
+        .into_iter()
+        .filter(|old| old.id != token.id && token_in_use.as_ref().is_none_or(|in_use| old.id != in_use.id))
+        .filter(|old| old.created.as_deref().and_then(from_rfc3339_to_utc_datetime).is_some_and(|created| created <= cutoff))
+        .collect();
+    output.message(format!("Tokens older than {} days ({}):", older_than_days, old_tokens.len()));
+    if old_tokens.is_empty() {
+        return Ok(());
+    }
+    output.print(&old_tokens)?;
+    if !confirm(&format!("Delete {} old tokens of service account [{}]?", old_tokens.len(), service_account.name), opt.yes) {
+        output.message("No delete confirmation. Skipping request..");
+        return Ok(());
+    }
+    let mut deleted = 0;
+    for old in &old_tokens {
+        match grafana_client.service_accounts().delete_token(id, old.id).await {
+            Ok(_) => deleted += 1,
+            Err(error) => eprintln!("{} [token id: {}, name: {}]", error, old.id, old.name),
+        }
+    }
+    output.message(format!("Deleted {} of {} old tokens", deleted, old_tokens.len()));
+    Ok(())
+}
+
+/// The token the cli most likely authenticates with when it is one of this service account: grafana does not tell which
+/// token a request used, but it was used by this very run and therefore most recently (none for basic auth or another account)
+async fn token_in_use(grafana_client: &GrafanaClient, id: u32, tokens: &[GetTokenResponse]) -> Option<GetTokenResponse> {
+    if matches!(grafana_client.auth, GrafanaAuth::Basic { .. }) {
+        return None;
+    }
+    // an unknown identity is taken as this service account to rather keep a token too many
+    if grafana_client.users().current().await.is_ok_and(|user| user.id != id) {
+        return None;
+    }
+    tokens
+        .iter()
+        .filter_map(|token| Some((token.last_used_at.as_deref().and_then(from_rfc3339_to_utc_datetime)?, token)))
+        .max_by_key(|(last_used_at, _)| *last_used_at)
+        .map(|(_, token)| token.clone())
+}
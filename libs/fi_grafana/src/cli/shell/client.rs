@@ -0,0 +1,133 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::Args;
+
+use crate::api::grafana::{GrafanaAuth, GrafanaClient};
+use crate::api::retry::RetryPolicy;
+use crate::cli::shell::config::{Config, Profile};
+use crate::error::GrafanaCliError;
+
+/// The global options that define how the cli talks to grafana
+#[derive(Debug, Args, Clone)]
+#[command(next_help_heading = "Connection")]
+pub struct ClientOptions {
+    /// Use the named connection profile of the config file (flags and env vars take precedence)
+    #[arg(long, global = true, env = "FI_PROFILE")]
+    pub profile: Option<String>,
+    /// The config file with the connection profiles [default: $XDG_CONFIG_HOME/fi/config.yaml]
+    #[arg(long, global = true, env = "FI_CONFIG")]
+    pub config: Option<PathBuf>,
+    /// The grafana api path [example: http://localhost:3000/api/]
+    #[arg(long, global = true, env = "GRAFANA_API_PATH")]
+    pub api: Option<String>,
+    /// Authenticate with this service account token
+    #[arg(long, global = true, env = "SERVICE_ACCOUNT_TOKEN", hide_env_values = true)]
+    pub token: Option<String>,
+    /// Authenticate with this legacy api key
+    #[arg(long, global = true, env = "GRAFANA_API_KEY", hide_env_values = true)]
+    pub api_key: Option<String>,
+    /// Authenticate with basic auth as this user (required for admin-only endpoints)
+    #[arg(long, global = true, env = "GRAFANA_USERNAME", requires = "password")]
+    pub username: Option<String>,
+    /// The password of the basic auth user
+    #[arg(long, global = true, env = "GRAFANA_PASSWORD", hide_env_values = true)]
+    pub password: Option<String>,
+    /// Send all requests to the organization with this id (X-Grafana-Org-Id header)
+    #[arg(long, global = true, env = "GRAFANA_ORG_ID")]
+    pub org_id: Option<u32>,
+    /// Accept invalid or self-signed tls certificates (only for local test instances)
+    #[arg(long, global = true, env = "FI_INSECURE")]
+    pub insecure: bool,
+    /// Trust this pem encoded ca certificate in addition to the system ones
+    #[arg(long, global = true, env = "FI_CA_CERT")]
+    pub ca_cert: Option<PathBuf>,
+    /// The number of attempts per request including the first one (1 disables retries)
+    #[arg(long, global = true, env = "FI_RETRY_MAX_ATTEMPTS", default_value_t = 3)]
+    pub retries: u32,
+    /// The initial delay in milliseconds before a retry which doubles with every attempt
+    #[arg(long, global = true, env = "FI_RETRY_BACKOFF_MS", default_value_t = 200)]
+    pub retry_backoff_ms: u64,
+    /// The upper bound in milliseconds of the delay between two attempts
+    #[arg(long, global = true, env = "FI_RETRY_MAX_BACKOFF_MS", default_value_t = 10_000)]
+    pub retry_max_backoff_ms: u64,
+    /// The timeout in seconds to establish a connection to grafana
+    #[arg(long, global = true, env = "FI_CONNECT_TIMEOUT_SECS", default_value_t = 10)]
+    pub connect_timeout: u64,
+    /// The timeout in seconds for a whole request including the response body
+    #[arg(long, global = true, env = "FI_REQUEST_TIMEOUT_SECS", default_value_t = 30)]
+    pub timeout: u64,
+}
+
+impl ClientOptions {
+    pub fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::new(
+            self.retries,
+            Duration::from_millis(self.retry_backoff_ms),
+            Duration::from_millis(self.retry_max_backoff_ms),
+        )
+    }
+
+    pub fn http_client(&self, profile: &Profile) -> Result<reqwest::Client, GrafanaCliError> {
+        let mut builder = reqwest::Client::builder()
+            .connect_timeout(Duration::from_secs(self.connect_timeout))
+            .timeout(Duration::from_secs(self.timeout))
+            .danger_accept_invalid_certs(self.insecure || profile.tls.insecure_skip_verify);
+        if let Some(ca_cert) = self.ca_cert.as_ref().or(profile.tls.ca_cert.as_ref()) {
+            builder = builder.add_root_certificate(reqwest::Certificate::from_pem(&fs::read(ca_cert)?)?);
+        }
+        Ok(builder.build()?)
+    }
+
+    /// Credentials given as flags or env vars win over the ones of the profile, basic auth wins over a token
+    pub fn auth(&self, profile: &Profile) -> Result<GrafanaAuth, GrafanaCliError> {
+        if let (Some(user), Some(password)) = (&self.username, &self.password) {
+            return Ok(GrafanaAuth::Basic { user: user.clone(), password: password.clone() });
+        }
+        if let Some(token) = &self.token {
+            return Ok(GrafanaAuth::ServiceAccountToken(token.clone()));
+        }
+        if let Some(api_key) = &self.api_key {
+            return Ok(GrafanaAuth::ApiKey(api_key.clone()));
+        }
+        match &profile.auth {
+            Some(auth) => auth.resolve(),
+            None => Err(GrafanaCliError::MissingGrafanaCredentials),
+        }
+    }
+
+    /// The client for the profile selected by --profile or FI_PROFILE (or the default profile)
+    pub fn grafana_client(&self) -> Result<GrafanaClient, GrafanaCliError> {
+        self.grafana_client_for_profile(self.profile.as_deref())
+    }
+
+    /// The client for the named profile (or the default one), the flags and env vars override its settings
+    pub fn grafana_client_for_profile(&self, name: Option<&str>) -> Result<GrafanaClient, GrafanaCliError> {
+        let profile = Config::load(self.config.clone())?.profile(name)?;
+        let api = self.api.clone().or(profile.url.clone()).ok_or(GrafanaCliError::MissingGrafanaApiPath)?;
+        let auth = self.auth(&profile)?;
+        let org_id = self.org_id.or(profile.org_id);
+        self.client_for(&profile, api, auth, org_id)
+    }
+
+    /// The client for the named profile with its url, credentials and org id only, the flags and env vars for them
+    /// are ignored (tls, timeouts and retries still apply). Migrate needs it since a single --api or --token would
+    /// point both its --from and --to client at the same instance.
+    pub fn grafana_client_from_profile_only(&self, name: &str) -> Result<GrafanaClient, GrafanaCliError> {
+        let profile = Config::load(self.config.clone())?.profile(Some(name))?;
+        let api = profile.url.clone().ok_or(GrafanaCliError::MissingGrafanaApiPath)?;
+        let auth = match &profile.auth {
+            Some(auth) => auth.resolve()?,
+            None => return Err(GrafanaCliError::MissingGrafanaCredentials),
+        };
+        let org_id = profile.org_id;
+        self.client_for(&profile, api, auth, org_id)
+    }
+
+    fn client_for(&self, profile: &Profile, api: String, auth: GrafanaAuth, org_id: Option<u32>) -> Result<GrafanaClient, GrafanaCliError> {
+        Ok(GrafanaClient::new(self.http_client(profile)?, api, auth)
+            .with_retry_policy(self.retry_policy())
+            .with_org_id(org_id))
+    }
+}
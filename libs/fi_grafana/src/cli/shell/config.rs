@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use serde::Deserialize;
+
+use crate::api::grafana::GrafanaAuth;
+use crate::error::GrafanaCliError;
+
+pub const CONFIG_FILE_NAME: &str = "config.yaml";
+
+/// The fi config file with named connection profiles
+///
+/// ```yaml
+/// default_profile: dev
+/// profiles:
+///   dev:
+///     url: http://localhost:3000/api/
+///     auth:
+///       mode: basic
+///       username: admin
+///       password: admin
+///   prod:
+///     url: https://grafana.example.com/api/
+///     org_id: 2
+///     auth:
+///       mode: token
+///       token_command: pass show grafana/prod
+///     tls:
+///       ca_cert: /etc/ssl/certs/example-ca.pem
+/// ```
+#[derive(Debug, Deserialize, Default)]
+pub struct Config {
+    /// The profile used when neither --profile nor FI_PROFILE is given
+    pub default_profile: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct Profile {
+    /// The grafana api path [example: http://localhost:3000/api/]
+    pub url: Option<String>,
+    pub auth: Option<ProfileAuth>,
+    /// The organization all requests of this profile target by default
+    pub org_id: Option<u32>,
+    #[serde(default)]
+    pub tls: TlsSettings,
+}
+
+/// A secret is either given inline or read from the stdout of a command (e.g. a password manager)
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum ProfileAuth {
+    Token { token: Option<String>, token_command: Option<String> },
+    ApiKey { api_key: Option<String>, api_key_command: Option<String> },
+    Basic { username: String, password: Option<String>, password_command: Option<String> },
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct TlsSettings {
+    /// Accept invalid or self-signed certificates (only for local test instances)
+    #[serde(default)]
+    pub insecure_skip_verify: bool,
+    /// A pem encoded ca certificate to trust in addition to the system ones
+    pub ca_cert: Option<PathBuf>,
+}
+
+impl Config {
+    /// $XDG_CONFIG_HOME/fi/config.yaml, falling back to ~/.config/fi/config.yaml (%APPDATA%\fi on windows)
+    pub fn default_path() -> Option<PathBuf> {
+        let config_home = env::var_os("XDG_CONFIG_HOME")
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .or_else(|| env::var_os("APPDATA").map(PathBuf::from))?;
+        Some(config_home.join("fi").join(CONFIG_FILE_NAME))
+    }
+
+    /// A missing config file is not an error since all settings can be given as flags or env vars
+    pub fn load(path: Option<PathBuf>) -> Result<Self, GrafanaCliError> {
+        let explicit = path.is_some();
+        let Some(path) = path.or_else(Self::default_path) else {
+            return Ok(Config::default());
+        };
+        if !explicit && !path.exists() {
+            return Ok(Config::default());
+        }
+        let content = fs::read_to_string(&path)?;
+        Ok(serde_yaml::from_str(&content)?)
+    }
+
+    /// The explicitly selected profile must exist, the default one is optional
+    pub fn profile(&self, name: Option<&str>) -> Result<Profile, GrafanaCliError> {
+        match name {
+            Some(name) => self.profiles.get(name).cloned().ok_or(GrafanaCliError::ProfileNotFound(name.to_string())),
+            None => Ok(self.default_profile.as_ref().and_then(|name| self.profiles.get(name)).cloned().unwrap_or_default()),
+        }
+    }
+}
+
+impl ProfileAuth {
+    pub fn resolve(&self) -> Result<GrafanaAuth, GrafanaCliError> {
+        match self {
+            ProfileAuth::Token { token, token_command } => {
+                Ok(GrafanaAuth::ServiceAccountToken(secret(token, token_command)?))
+            }
+            ProfileAuth::ApiKey { api_key, api_key_command } => {
+                Ok(GrafanaAuth::ApiKey(secret(api_key, api_key_command)?))
+            }
+            ProfileAuth::Basic { username, password, password_command } => {
+                Ok(GrafanaAuth::Basic { user: username.clone(), password: secret(password, password_command)? })
+            }
+        }
+    }
+}
+
+fn secret(value: &Option<String>, command: &Option<String>) -> Result<String, GrafanaCliError> {
+    if let Some(value) = value {
+        return Ok(value.clone());
+    }
+    match command {
+        Some(command) => run_secret_command(command),
+        None => Err(GrafanaCliError::MissingGrafanaCredentials),
+    }
+}
+
+fn run_secret_command(command: &str) -> Result<String, GrafanaCliError> {
+    let output = if cfg!(windows) {
+        Command::new("cmd").args(["/C", command]).output()?
+    } else {
+        Command::new("sh").args(["-c", command]).output()?
+    };
+    if !output.status.success() {
+        return Err(GrafanaCliError::SecretCommandFailed(command.to_string(), String::from_utf8_lossy(&output.stderr).trim().to_string()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
@@ -1,8 +1,8 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
 
 use crate::error::GrafanaCliError;
 
-pub const DATETIME_FORMAT: &'static str = "%Y-%m-%d %H:%M";
+pub const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M";
 
 // todo: ask yourself if this is really necessary
 pub fn parse_datetime_to_epoch_time_millis(datetime: &Option<String>) -> Option<i64> {
@@ -19,10 +19,49 @@ pub fn parse_datetime_to_epoch_time_millis(datetime: &Option<String>) -> Option<
     }
 }
 
+/// Parses a local datetime [format: %Y-%m-%d %H:%M], the same zone the datetimes are rendered in
 pub fn from_datetime_to_epoch_time_millis(datetime_str: &str) -> Result<i64, GrafanaCliError> {
-    let datetime = NaiveDateTime::parse_from_str(datetime_str, DATETIME_FORMAT);
-    match datetime {
-        Ok(datetime) => Ok(datetime.and_utc().timestamp_millis()),
-        Err(error) => Err(GrafanaCliError::CanNotParseDateTimeToEpochTimeMillis(error))
+    let datetime = NaiveDateTime::parse_from_str(datetime_str, DATETIME_FORMAT)?;
+    // a datetime repeated when the clocks go back is taken at its first occurrence
+    match Local.from_local_datetime(&datetime).earliest() {
+        Some(datetime) => Ok(datetime.timestamp_millis()),
+        None => Err(GrafanaCliError::NonExistentLocalDateTime(datetime_str.to_string())),
     }
-}
\ No newline at end of file
+}
+
+/// Renders epoch time millis as local datetime [format: %Y-%m-%d %H:%M]
+pub fn from_epoch_time_millis_to_local_datetime(epoch_time_millis: i64) -> String {
+    match DateTime::from_timestamp_millis(epoch_time_millis) {
+        Some(datetime) => datetime.with_timezone(&Local).format(DATETIME_FORMAT).to_string(),
+        None => epoch_time_millis.to_string(),
+    }
+}
+
+/// Parses an RFC 3339 datetime as received from grafana (e.g. 2024-09-03T10:31:02Z)
+pub fn from_rfc3339_to_utc_datetime(datetime: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(datetime).ok().map(|datetime| datetime.with_timezone(&Utc))
+}
+
+/// Renders an RFC 3339 datetime as local datetime [format: %Y-%m-%d %H:%M]
+pub fn from_rfc3339_to_local_datetime(datetime: &str) -> String {
+    match from_rfc3339_to_utc_datetime(datetime) {
+        Some(datetime) => datetime.with_timezone(&Local).format(DATETIME_FORMAT).to_string(),
+        None => datetime.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_datetime_is_rendered_as_it_was_entered() {
+        let millis = from_datetime_to_epoch_time_millis("2024-09-03 11:30").unwrap();
+        assert_eq!(from_epoch_time_millis_to_local_datetime(millis), "2024-09-03 11:30");
+    }
+
+    #[test]
+    fn a_datetime_in_another_format_is_rejected() {
+        assert!(from_datetime_to_epoch_time_millis("03.09.2024 11:30").is_err());
+    }
+}
@@ -0,0 +1,31 @@
+use crate::api::dry_run::DryRunRequest;
+use crate::api::grafana::GrafanaClient;
+use crate::cli::shell::output::{OutputFormat, Tabular};
+use crate::error::GrafanaCliError;
+
+impl Tabular for DryRunRequest {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["METHOD", "URL", "BODY"]
+    }
+
+    fn row(&self, wide: bool) -> Vec<String> {
+        let body = match &self.body {
+            Some(body) if wide => serde_json::to_string_pretty(body).unwrap_or_default(),
+            Some(body) => body.to_string(),
+            None => "-".to_string(),
+        };
+        vec![self.method.clone(), self.url.clone(), body]
+    }
+}
+
+/// Prints the requests a dry run recorded instead of sending them, followed by their count (to stderr for json/yaml)
+pub fn print_dry_run(grafana_client: &GrafanaClient, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let Some(dry_run) = &grafana_client.dry_run else {
+        return Ok(());
+    };
+    let requests = dry_run.requests();
+    output.message(format!("Dry run, requests not sent ({}):", requests.len()));
+    output.print_aside(&requests)?;
+    output.message(format!("{} requests would have been sent", requests.len()));
+    Ok(())
+}
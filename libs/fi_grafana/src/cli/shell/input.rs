@@ -1,22 +1,25 @@
+use std::fmt::Display;
 use std::io;
 use std::io::Write;
+use std::str::FromStr;
 
 use crate::error::GrafanaCliError;
 
-pub enum UserInput {
-    Number(u32),
-    Text(String),
-}
-
+/// Prompts for the value unless it was given upfront, an unparsable input is reported and taken as no value
 pub fn prompt_option<T>(prompt: &str, opt: &Option<T>) -> Option<T>
 where
-    T: Clone + From<UserInput>,
+    T: Clone + FromStr,
+    T::Err: Display,
 {
     if opt.is_none() {
         match user_input(prompt) {
-            Ok(input) => {
-                Some(T::from(input))
-            }
+            Ok(input) => match input.parse::<T>() {
+                Ok(value) => Some(value),
+                Err(error) => {
+                    eprintln!("invalid input [{}]: {}", input, error);
+                    None
+                }
+            },
             Err(error) => {
                 eprintln!("{}", error);
                 None
@@ -27,37 +30,22 @@ where
     }
 }
 
-pub fn user_input(prompt: &str) -> Result<UserInput, GrafanaCliError> {
+/// Reads one line from stdin as typed without the surrounding whitespace, digits stay text (e.g. a password or login)
+pub fn user_input(prompt: &str) -> Result<String, GrafanaCliError> {
     print!("{}", prompt);
     io::stdout().flush()?;
     let mut input = String::new();
-    match io::stdin().read_line(&mut input) {
-        Ok(_) => {
-            let trimmed_input = input.trim();
-            if let Ok(number) = trimmed_input.parse::<u32>() {
-                Ok(UserInput::Number(number))
-            } else {
-                Ok(UserInput::Text(trimmed_input.to_string()))
-            }
-        }
-        Err(error) => Err(GrafanaCliError::IO(error))
-    }
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
 }
 
-impl From<UserInput> for u32 {
-    fn from(input: UserInput) -> Self {
-        match input {
-            UserInput::Number(n) => n,
-            UserInput::Text(_) => panic!("Expected a number, but got a string while parsing the user input from stdin")
-        }
+/// Asks for a (y/n) confirmation unless it was given upfront (e.g. with a --yes flag)
+pub fn confirm(prompt: &str, yes: bool) -> bool {
+    if yes {
+        return true;
     }
-}
-
-impl From<UserInput> for String {
-    fn from(input: UserInput) -> Self {
-        match input {
-            UserInput::Text(s) => s,
-            UserInput::Number(_) => panic!("Expected a string, but got a number while parsing the user input from stdin"),
-        }
+    match user_input(&format!("{} (y/n) ", prompt)) {
+        Ok(input) => input.eq("y"),
+        Err(_) => false,
     }
-}
\ No newline at end of file
+}
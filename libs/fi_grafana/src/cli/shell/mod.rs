@@ -1,3 +1,7 @@
+pub mod client;
+pub mod config;
+pub mod dry_run;
 pub mod input;
+pub mod output;
 pub mod date;
 pub mod request;
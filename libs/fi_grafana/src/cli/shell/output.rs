@@ -0,0 +1,116 @@
+use std::fmt::Display;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::error::GrafanaCliError;
+
+/// The format in which the cli prints the received resources
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// An aligned table with the most important columns
+    #[default]
+    Table,
+    /// An aligned table with all available columns
+    Wide,
+    /// Pretty printed json (one document per resource or list)
+    Json,
+    /// Yaml (one document per resource or list)
+    Yaml,
+}
+
+/// A resource that can be rendered as a row of an aligned table
+pub trait Tabular {
+    /// The column headers, wide adds the less important columns
+    fn headers(wide: bool) -> Vec<&'static str>;
+    /// The cells of this resource in the order of the headers
+    fn row(&self, wide: bool) -> Vec<String>;
+}
+
+impl OutputFormat {
+    /// Whether the output is meant to be read by humans rather than by scripts
+    pub fn is_human(&self) -> bool {
+        matches!(self, OutputFormat::Table | OutputFormat::Wide)
+    }
+
+    /// Prints a list of resources as one table or as one json/yaml array
+    pub fn print<T: Serialize + Tabular>(&self, items: &[T]) -> Result<(), GrafanaCliError> {
+        match self {
+            OutputFormat::Table => println!("{}", table(items, false)),
+            OutputFormat::Wide => println!("{}", table(items, true)),
+            OutputFormat::Json => println!("{}", to_json(items)?),
+            OutputFormat::Yaml => print!("---\n{}", to_yaml(items)?),
+        }
+        Ok(())
+    }
+
+    /// Prints a list of resources like print, but to stderr for json/yaml to keep the document on stdout the only one
+    pub fn print_aside<T: Serialize + Tabular>(&self, items: &[T]) -> Result<(), GrafanaCliError> {
+        match self {
+            OutputFormat::Table | OutputFormat::Wide => return self.print(items),
+            OutputFormat::Json => eprintln!("{}", to_json(items)?),
+            OutputFormat::Yaml => eprint!("---\n{}", to_yaml(items)?),
+        }
+        Ok(())
+    }
+
+    /// Prints a single resource as a table with one row or as one json/yaml object
+    pub fn print_one<T: Serialize + Tabular>(&self, item: &T) -> Result<(), GrafanaCliError> {
+        match self {
+            OutputFormat::Table | OutputFormat::Wide => return self.print(std::slice::from_ref(item)),
+            OutputFormat::Json => println!("{}", to_json(item)?),
+            OutputFormat::Yaml => print!("---\n{}", to_yaml(item)?),
+        }
+        Ok(())
+    }
+
+    /// Informational messages go to stdout for humans but to stderr for json/yaml to keep stdout parseable
+    pub fn message(&self, message: impl Display) {
+        if self.is_human() {
+            println!("{}", message);
+        } else {
+            eprintln!("{}", message);
+        }
+    }
+}
+
+fn to_json<T: Serialize + ?Sized>(value: &T) -> Result<String, GrafanaCliError> {
+    serde_json::to_string_pretty(value).map_err(|error| GrafanaCliError::CanNotRenderOutput(error.to_string()))
+}
+
+fn to_yaml<T: Serialize + ?Sized>(value: &T) -> Result<String, GrafanaCliError> {
+    serde_yaml::to_string(value).map_err(|error| GrafanaCliError::CanNotRenderOutput(error.to_string()))
+}
+
+/// Left aligns every column to its widest cell with three spaces in between
+pub fn table<T: Tabular>(items: &[T], wide: bool) -> String {
+    let headers = T::headers(wide);
+    let rows: Vec<Vec<String>> = items.iter().map(|item| item.row(wide)).collect();
+    let mut widths: Vec<usize> = headers.iter().map(|header| header.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut lines = vec![format_row(headers.iter().map(|header| header.to_string()), &widths)];
+    lines.extend(rows.into_iter().map(|row| format_row(row.into_iter(), &widths)));
+    lines.join("\n")
+}
+
+fn format_row(cells: impl Iterator<Item = String>, widths: &[usize]) -> String {
+    cells
+        .zip(widths)
+        .map(|(cell, width)| format!("{:width$}", cell, width = width))
+        .collect::<Vec<String>>()
+        .join("   ")
+        .trim_end()
+        .to_string()
+}
+
+/// Renders an optional cell with a dash as placeholder
+pub fn cell<T: Display>(value: &Option<T>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => "-".to_string(),
+    }
+}
@@ -2,22 +2,53 @@ use clap::{Args, Parser, Subcommand};
 
 use crate::api::grafana::GrafanaClient;
 use crate::cli::annotation::add::handle_add_annotation;
+use crate::cli::annotation::delete::handle_del_annotation;
+use crate::cli::annotation::get::handle_get_annotation;
 use crate::cli::annotation::options::AnnotationOptions;
+use crate::cli::annotation::set::handle_set_annotation;
 use crate::cli::dashboard::add::handle_add_dashboard;
+use crate::cli::dashboard::delete::handle_del_dashboard;
+use crate::cli::dashboard::diff::handle_diff_dashboard;
 use crate::cli::dashboard::get::handle_get_dashboard;
-use crate::cli::dashboard::options::DashboardOptions;
+use crate::cli::dashboard::options::{DashboardDiffOptions, DashboardOptions};
+use crate::cli::dashboard::set::handle_set_dashboard;
 use crate::cli::folder::add::handle_add_folder;
+use crate::cli::folder::delete::handle_del_folder;
 use crate::cli::folder::get::handle_get_folder;
 use crate::cli::folder::options::FolderOptions;
+use crate::cli::folder::permission::get::handle_get_folder_permissions;
 use crate::cli::folder::permission::options::FolderPermissionOptions;
 use crate::cli::folder::permission::set::handle_set_folder_permissions;
-use crate::cli::role::Role;
-use crate::cli::service_account::ServiceAccount;
+use crate::cli::folder::set::handle_set_folder;
+use crate::cli::migrate::options::MigrateOptions;
+use crate::cli::role::add::handle_add_role;
+use crate::cli::role::delete::handle_del_role;
+use crate::cli::role::get::handle_get_role;
+use crate::cli::role::options::RoleOptions;
+use crate::cli::role::set::handle_set_role;
+use crate::cli::service_account::add::handle_add_service_account;
+use crate::cli::service_account::delete::handle_del_service_account;
+use crate::cli::service_account::get::handle_get_service_account;
+use crate::cli::service_account::options::ServiceAccountOptions;
+use crate::cli::service_account::set::handle_set_service_account;
+use crate::cli::search::options::SearchOptions;
+use crate::cli::shell::client::ClientOptions;
+use crate::cli::shell::output::OutputFormat;
 use crate::cli::team::add::handle_add_team;
 use crate::cli::team::delete::handle_del_team;
 use crate::cli::team::get::handle_get_team;
+use crate::cli::team::member::add::handle_add_team_member;
+use crate::cli::team::member::delete::handle_del_team_member;
+use crate::cli::team::member::get::handle_get_team_member;
+use crate::cli::team::member::options::TeamMemberOptions;
 use crate::cli::team::options::TeamOptions;
-use crate::cli::user::User;
+use crate::cli::team::set::handle_set_team;
+use crate::cli::user::add::handle_add_user;
+use crate::cli::user::delete::handle_del_user;
+use crate::cli::user::get::handle_get_user;
+use crate::cli::user::options::UserOptions;
+use crate::cli::user::set::handle_set_user;
+use crate::error::GrafanaCliError;
 
 #[derive(Parser)]
 #[clap(version, about, long_about = None)]
@@ -25,6 +56,14 @@ use crate::cli::user::User;
 pub struct Cli {
     #[clap(subcommand)]
     pub method: RequestMethod,
+    /// The format in which the received resources are printed
+    #[arg(long, global = true, value_enum, env = "FI_OUTPUT", default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+    /// Sends only reading requests and prints the creating, updating and deleting ones instead
+    #[arg(long, global = true, env = "FI_DRY_RUN", default_value_t = false)]
+    pub dry_run: bool,
+    #[clap(flatten)]
+    pub client: ClientOptions,
 }
 
 #[derive(Subcommand)]
@@ -37,6 +76,14 @@ pub enum RequestMethod {
     Set(SetRequest),
     #[clap(arg_required_else_help = true)]
     Del(DelRequest),
+    /// Compares two versions of a resource
+    #[clap(arg_required_else_help = true)]
+    Diff(DiffRequest),
+    /// Copies folders, dashboards, permissions, teams, library panels and annotations from one profile's instance to another
+    #[clap(arg_required_else_help = true)]
+    Migrate(MigrateOptions),
+    /// Finds dashboards and folders by title, tags, stars and folders
+    Search(SearchOptions),
 }
 
 #[derive(Debug, Args)]
@@ -63,143 +110,276 @@ pub struct DelRequest {
     pub resource: NamedResource,
 }
 
+#[derive(Debug, Args)]
+pub struct DiffRequest {
+    #[clap(subcommand)]
+    pub resource: DiffResource,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum DiffResource {
+    Dashboard(DashboardDiffOptions),
+    D(DashboardDiffOptions),
+}
+
 #[derive(Debug, Subcommand)]
 pub enum NamedResource {
     Annotation(AnnotationOptions),
     A(AnnotationOptions),
     Dashboard(DashboardOptions),
     D(DashboardOptions),
-    ServiceAccount(ServiceAccount),
-    SA(ServiceAccount),
-    User(User),
-    U(User),
+    ServiceAccount(ServiceAccountOptions),
+    SA(ServiceAccountOptions),
+    User(UserOptions),
+    U(UserOptions),
     Team(TeamOptions),
     T(TeamOptions),
+    TeamMember(TeamMemberOptions),
+    TM(TeamMemberOptions),
     Folder(FolderOptions),
     F(FolderOptions),
     Permission(FolderPermissionOptions),
     P(FolderPermissionOptions),
-    Role(Role),
-    R(Role),
+    Role(RoleOptions),
+    R(RoleOptions),
 }
 
-pub async fn handle_add(grafana_client: &GrafanaClient, request: AddRequest) {
-    use NamedResource;
+pub async fn handle_add(grafana_client: &GrafanaClient, request: AddRequest, output: OutputFormat) -> Result<(), GrafanaCliError> {
     match request.resource {
         NamedResource::Annotation(opt) => {
-            handle_add_annotation(grafana_client, &opt).await;
+            handle_add_annotation(grafana_client, &opt, output).await?;
         }
         NamedResource::A(opt) => {
-            handle_add_annotation(grafana_client, &opt).await;
+            handle_add_annotation(grafana_client, &opt, output).await?;
         }
         NamedResource::Dashboard(mut opt) => {
-            handle_add_dashboard(grafana_client, &mut opt).await.expect("failure while adding a new dashboard");
+            handle_add_dashboard(grafana_client, &mut opt, output).await?;
         }
         NamedResource::D(mut opt) => {
-            handle_add_dashboard(grafana_client, &mut opt).await.expect("failure while adding a new dashboard");
+            handle_add_dashboard(grafana_client, &mut opt, output).await?;
+        }
+        NamedResource::ServiceAccount(opt) => {
+            handle_add_service_account(grafana_client, &opt, output).await?;
+        }
+        NamedResource::SA(opt) => {
+            handle_add_service_account(grafana_client, &opt, output).await?;
+        }
+        NamedResource::User(opt) => {
+            handle_add_user(grafana_client, &opt, output).await?;
+        }
+        NamedResource::U(opt) => {
+            handle_add_user(grafana_client, &opt, output).await?;
         }
-        NamedResource::ServiceAccount(_) => {}
-        NamedResource::SA(_) => {}
-        NamedResource::User(_) => {}
-        NamedResource::U(_) => {}
         NamedResource::Team(opt) => {
-            handle_add_team(grafana_client, &opt).await;
+            handle_add_team(grafana_client, &opt, output).await?;
         }
         NamedResource::T(opt) => {
-            handle_add_team(grafana_client, &opt).await;
+            handle_add_team(grafana_client, &opt, output).await?;
+        }
+        NamedResource::TeamMember(opt) => {
+            handle_add_team_member(grafana_client, &opt, output).await?;
+        }
+        NamedResource::TM(opt) => {
+            handle_add_team_member(grafana_client, &opt, output).await?;
         }
         NamedResource::Folder(opt) => {
-            handle_add_folder(grafana_client, &opt).await.expect("failure while adding a new folder");
+            handle_add_folder(grafana_client, &opt, output).await?;
         }
         NamedResource::F(opt) => {
-            handle_add_folder(grafana_client, &opt).await.expect("failure while adding a new folder");
+            handle_add_folder(grafana_client, &opt, output).await?;
         }
         NamedResource::Permission(_) => {}
         NamedResource::P(_) => {}
-        NamedResource::Role(_) => {}
-        NamedResource::R(_) => {}
+        NamedResource::Role(opt) => {
+            handle_add_role(grafana_client, &opt, output).await?;
+        }
+        NamedResource::R(opt) => {
+            handle_add_role(grafana_client, &opt, output).await?;
+        }
     }
+    Ok(())
 }
 
-pub async fn handle_get(grafana_client: &GrafanaClient, request: GetRequest) {
+pub async fn handle_get(grafana_client: &GrafanaClient, request: GetRequest, output: OutputFormat) -> Result<(), GrafanaCliError> {
     match request.resource {
-        NamedResource::Annotation(_) => {}
-        NamedResource::A(_) => {}
+        NamedResource::Annotation(opt) => {
+            handle_get_annotation(grafana_client, &opt, output).await?;
+        }
+        NamedResource::A(opt) => {
+            handle_get_annotation(grafana_client, &opt, output).await?;
+        }
         NamedResource::Dashboard(opt) => {
-            handle_get_dashboard(grafana_client, &opt).await;
+            handle_get_dashboard(grafana_client, &opt, output).await?;
         }
         NamedResource::D(opt) => {
-            handle_get_dashboard(grafana_client, &opt).await;
+            handle_get_dashboard(grafana_client, &opt, output).await?;
+        }
+        NamedResource::ServiceAccount(opt) => {
+            handle_get_service_account(grafana_client, &opt, output).await?;
+        }
+        NamedResource::SA(opt) => {
+            handle_get_service_account(grafana_client, &opt, output).await?;
+        }
+        NamedResource::User(opt) => {
+            handle_get_user(grafana_client, &opt, output).await?;
+        }
+        NamedResource::U(opt) => {
+            handle_get_user(grafana_client, &opt, output).await?;
         }
-        NamedResource::ServiceAccount(_) => {}
-        NamedResource::SA(_) => {}
-        NamedResource::User(_) => {}
-        NamedResource::U(_) => {}
         NamedResource::Team(opt) => {
-            handle_get_team(grafana_client, &opt).await;
+            handle_get_team(grafana_client, &opt, output).await?;
         }
         NamedResource::T(opt) => {
-            handle_get_team(grafana_client, &opt).await;
+            handle_get_team(grafana_client, &opt, output).await?;
+        }
+        NamedResource::TeamMember(opt) => {
+            handle_get_team_member(grafana_client, &opt, output).await?;
+        }
+        NamedResource::TM(opt) => {
+            handle_get_team_member(grafana_client, &opt, output).await?;
         }
         NamedResource::Folder(opt) => {
-            handle_get_folder(grafana_client, &opt).await;
+            handle_get_folder(grafana_client, &opt, output).await?;
         }
         NamedResource::F(opt) => {
-            handle_get_folder(grafana_client, &opt).await;
+            handle_get_folder(grafana_client, &opt, output).await?;
+        }
+        NamedResource::Permission(opt) => {
+            handle_get_folder_permissions(grafana_client, &opt, output).await?;
+        }
+        NamedResource::P(opt) => {
+            handle_get_folder_permissions(grafana_client, &opt, output).await?;
+        }
+        NamedResource::Role(opt) => {
+            handle_get_role(grafana_client, &opt, output).await?;
+        }
+        NamedResource::R(opt) => {
+            handle_get_role(grafana_client, &opt, output).await?;
         }
-        NamedResource::Permission(_) => {}
-        NamedResource::P(_) => {}
-        NamedResource::Role(_) => {}
-        NamedResource::R(_) => {}
     }
+    Ok(())
 }
 
-pub async fn handle_set(grafana_client: &GrafanaClient, request: SetRequest) {
+pub async fn handle_set(grafana_client: &GrafanaClient, request: SetRequest, output: OutputFormat) -> Result<(), GrafanaCliError> {
     match request.resource {
-        NamedResource::Annotation(_) => {}
-        NamedResource::A(_) => {}
-        NamedResource::Dashboard(_) => {}
-        NamedResource::D(_) => {}
-        NamedResource::ServiceAccount(_) => {}
-        NamedResource::SA(_) => {}
-        NamedResource::User(_) => {}
-        NamedResource::U(_) => {}
-        NamedResource::Team(_) => {}
-        NamedResource::T(_) => {}
-        NamedResource::Folder(_) => {}
-        NamedResource::F(_) => {}
+        NamedResource::Annotation(opt) => {
+            handle_set_annotation(grafana_client, &opt, output).await?;
+        }
+        NamedResource::A(opt) => {
+            handle_set_annotation(grafana_client, &opt, output).await?;
+        }
+        NamedResource::Dashboard(opt) => {
+            handle_set_dashboard(grafana_client, &opt, output).await?;
+        }
+        NamedResource::D(opt) => {
+            handle_set_dashboard(grafana_client, &opt, output).await?;
+        }
+        NamedResource::ServiceAccount(opt) => {
+            handle_set_service_account(grafana_client, &opt, output).await?;
+        }
+        NamedResource::SA(opt) => {
+            handle_set_service_account(grafana_client, &opt, output).await?;
+        }
+        NamedResource::User(opt) => {
+            handle_set_user(grafana_client, &opt, output).await?;
+        }
+        NamedResource::U(opt) => {
+            handle_set_user(grafana_client, &opt, output).await?;
+        }
+        NamedResource::Team(opt) => {
+            handle_set_team(grafana_client, &opt, output).await?;
+        }
+        NamedResource::T(opt) => {
+            handle_set_team(grafana_client, &opt, output).await?;
+        }
+        NamedResource::TeamMember(_) => {}
+        NamedResource::TM(_) => {}
+        NamedResource::Folder(opt) => {
+            handle_set_folder(grafana_client, &opt, output).await?;
+        }
+        NamedResource::F(opt) => {
+            handle_set_folder(grafana_client, &opt, output).await?;
+        }
         NamedResource::Permission(opt) => {
-            handle_set_folder_permissions(grafana_client, &opt).await;
+            handle_set_folder_permissions(grafana_client, &opt, output).await?;
         }
         NamedResource::P(opt) => {
-            handle_set_folder_permissions(grafana_client, &opt).await;
+            handle_set_folder_permissions(grafana_client, &opt, output).await?;
+        }
+        NamedResource::Role(opt) => {
+            handle_set_role(grafana_client, &opt, output).await?;
+        }
+        NamedResource::R(opt) => {
+            handle_set_role(grafana_client, &opt, output).await?;
         }
-        NamedResource::Role(_) => {}
-        NamedResource::R(_) => {}
     }
+    Ok(())
 }
 
-pub async fn handle_del(grafana_client: &GrafanaClient, request: DelRequest) {
+pub async fn handle_del(grafana_client: &GrafanaClient, request: DelRequest, output: OutputFormat) -> Result<(), GrafanaCliError> {
     match request.resource {
-        NamedResource::Annotation(_) => {}
-        NamedResource::A(_) => {}
-        NamedResource::Dashboard(_) => {}
-        NamedResource::D(_) => {}
-        NamedResource::ServiceAccount(_) => {}
-        NamedResource::SA(_) => {}
-        NamedResource::User(_) => {}
-        NamedResource::U(_) => {}
+        NamedResource::Annotation(opt) => {
+            handle_del_annotation(grafana_client, &opt, output).await?;
+        }
+        NamedResource::A(opt) => {
+            handle_del_annotation(grafana_client, &opt, output).await?;
+        }
+        NamedResource::Dashboard(opt) => {
+            handle_del_dashboard(grafana_client, &opt, output).await?;
+        }
+        NamedResource::D(opt) => {
+            handle_del_dashboard(grafana_client, &opt, output).await?;
+        }
+        NamedResource::ServiceAccount(opt) => {
+            handle_del_service_account(grafana_client, &opt, output).await?;
+        }
+        NamedResource::SA(opt) => {
+            handle_del_service_account(grafana_client, &opt, output).await?;
+        }
+        NamedResource::User(opt) => {
+            handle_del_user(grafana_client, &opt, output).await?;
+        }
+        NamedResource::U(opt) => {
+            handle_del_user(grafana_client, &opt, output).await?;
+        }
         NamedResource::Team(opt) => {
-            handle_del_team(grafana_client, &opt).await;
+            handle_del_team(grafana_client, &opt, output).await?;
         }
         NamedResource::T(opt) => {
-            handle_del_team(grafana_client, &opt).await;
+            handle_del_team(grafana_client, &opt, output).await?;
+        }
+        NamedResource::TeamMember(opt) => {
+            handle_del_team_member(grafana_client, &opt, output).await?;
+        }
+        NamedResource::TM(opt) => {
+            handle_del_team_member(grafana_client, &opt, output).await?;
+        }
+        NamedResource::Folder(opt) => {
+            handle_del_folder(grafana_client, &opt, output).await?;
+        }
+        NamedResource::F(opt) => {
+            handle_del_folder(grafana_client, &opt, output).await?;
         }
-        NamedResource::Folder(_) => {}
-        NamedResource::F(_) => {}
         NamedResource::Permission(_) => {}
         NamedResource::P(_) => {}
-        NamedResource::Role(_) => {}
-        NamedResource::R(_) => {}
+        NamedResource::Role(opt) => {
+            handle_del_role(grafana_client, &opt, output).await?;
+        }
+        NamedResource::R(opt) => {
+            handle_del_role(grafana_client, &opt, output).await?;
+        }
+    }
+    Ok(())
+}
+
+pub async fn handle_diff(grafana_client: &GrafanaClient, request: DiffRequest, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    match request.resource {
+        DiffResource::Dashboard(opt) => {
+            handle_diff_dashboard(grafana_client, &opt, output).await?;
+        }
+        DiffResource::D(opt) => {
+            handle_diff_dashboard(grafana_client, &opt, output).await?;
+        }
     }
-}
\ No newline at end of file
+    Ok(())
+}
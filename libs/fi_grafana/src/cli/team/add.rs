@@ -1,83 +1,41 @@
-use serde::{Deserialize, Serialize};
-
+use crate::api::folder::{FolderPermissionItem, PermissionLevel};
 use crate::api::grafana::GrafanaClient;
+use crate::api::team::{AddTeamRequest, AddTeamResponse};
 use crate::cli::folder::add::handle_add_folder;
 use crate::cli::folder::options::FolderOptions;
-use crate::cli::folder::permission::set::{FolderPermissionItem, set_folder_permissions};
+use crate::cli::folder::permission::set::set_folder_permissions;
 use crate::cli::shell::input::prompt_option;
+use crate::cli::shell::output::{cell, OutputFormat, Tabular};
 use crate::cli::team::options::TeamOptions;
 use crate::error::GrafanaCliError;
 
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct AddTeamRequest {
-    pub name: String,
-    pub email: Option<String>,
-    pub org_id: Option<u32>,
-}
+impl Tabular for AddTeamResponse {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["TEAM_ID", "MESSAGE"]
+    }
 
-#[derive(Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct AddTeamResponse {
-    pub message: String,
-    pub team_id: Option<u32>,
+    fn row(&self, _wide: bool) -> Vec<String> {
+        vec![cell(&self.team_id), self.message.clone()]
+    }
 }
 
-pub async fn handle_add_team(grafana_client: &GrafanaClient, opt: &TeamOptions) {
+pub async fn handle_add_team(grafana_client: &GrafanaClient, opt: &TeamOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
     let team_name = prompt_option("Enter a team name: ", &opt.name);
     let (admin_team_id, viewer_team_id) = if let Some(name) = &team_name {
-        let admin_team_id = match add_team(grafana_client, name.clone(), opt).await {
-            Ok(response) => {
-                response.team_id
-            }
-            Err(error) => {
-                eprintln!("{}", error);
-                None
-            }
-        };
-        let viewer_team_id = match add_team(grafana_client, format!("{}-{}", name, "Viewer"), opt).await {
-            Ok(response) => {
-                response.team_id
-            }
-            Err(error) => {
-                eprintln!("{}", error);
-                None
-            }
-        };
+        let admin_team_id = add_team(grafana_client, name.clone(), opt, output).await?.team_id;
+        let viewer_team_id = add_team(grafana_client, format!("{}-{}", name, "Viewer"), opt, output).await?.team_id;
         (admin_team_id, viewer_team_id)
     } else {
         (None, None)
     };
-    let folder_uid = if let Some(_) = admin_team_id {
+    let folder_uid = if admin_team_id.is_some() {
         if opt.folder_title.is_some() {
             // todo: reduce duplication
-            match handle_add_folder(grafana_client, &FolderOptions::from_title(opt.folder_title.clone())).await {
-                Ok(response) => {
-                    Some(response.uid)
-                }
-                Err(error) => {
-                    eprintln!("{}", error);
-                    None
-                }
-            }
+            Some(handle_add_folder(grafana_client, &FolderOptions::from_title(opt.folder_title.clone()), output).await?.uid)
+        } else if opt.directory && team_name.is_some() {
+            Some(handle_add_folder(grafana_client, &FolderOptions::from_title(team_name.clone()), output).await?.uid)
         } else {
-            if opt.directory {
-                if team_name.is_some() {
-                    match handle_add_folder(grafana_client, &FolderOptions::from_title(team_name.clone())).await {
-                        Ok(response) => {
-                            Some(response.uid)
-                        }
-                        Err(error) => {
-                            eprintln!("{}", error);
-                            None
-                        }
-                    }
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
+            None
         }
     } else {
         None
@@ -85,45 +43,35 @@ pub async fn handle_add_team(grafana_client: &GrafanaClient, opt: &TeamOptions)
     if let Some(folder_uid) = folder_uid {
         let mut items = FolderPermissionItem::default_permissions_items();
         if let Some(admin) = admin_team_id {
-            items.push(FolderPermissionItem::team(admin, 4));
+            items.push(FolderPermissionItem::team(admin, PermissionLevel::Admin.value()));
         }
         if let Some(viewer) = viewer_team_id {
-            items.push(FolderPermissionItem::team(viewer, 1));
-        }
-        match set_folder_permissions(grafana_client, Some(folder_uid), items).await {
-            Ok(_) => {}
-            Err(_) => {}
+            items.push(FolderPermissionItem::team(viewer, PermissionLevel::View.value()));
         }
+        set_folder_permissions(grafana_client, Some(folder_uid), items, output).await?;
     }
+    Ok(())
 }
 
-async fn add_team(grafana_client: &GrafanaClient, name: String, opt: &TeamOptions) -> Result<AddTeamResponse, GrafanaCliError> {
+async fn add_team(grafana_client: &GrafanaClient, name: String, opt: &TeamOptions, output: OutputFormat) -> Result<AddTeamResponse, GrafanaCliError> {
     let request = AddTeamRequest {
         name: name.clone(),
         email: opt.email.clone(),
-        org_id: opt.org_id,
+        org_id: grafana_client.org_id,
     };
-    match post_add_team(grafana_client, &request).await {
+    match grafana_client.teams().create(&request).await {
         Ok(response) => {
-            if let Some(team_id) = response.team_id {
-                println!("{} [id: {}, name: {}]", response.message, team_id, name);
+            if response.team_id.is_some() {
+                output.print_one(&response)?;
                 Ok(response)
             } else {
-                println!("No team created! Reason: {}", response.message);
+                output.message(format!("No team created! Reason: {}", response.message));
                 Err(GrafanaCliError::NoTeamIdReceivedFromGrafanaOnTeamCreation)
             }
         }
         Err(error) => {
-            eprintln!("No team created! error: {}", error);
-            Err(GrafanaCliError::Request(error))
+            output.message(format!("No team created! [name: {}]", name));
+            Err(error)
         }
     }
 }
-
-async fn post_add_team(grafana_client: &GrafanaClient, request: &AddTeamRequest) -> Result<AddTeamResponse, reqwest::Error> {
-    match grafana_client
-        .post("teams", request).await {
-        Ok(response) => Ok(response.json::<AddTeamResponse>().await?),
-        Err(error) => Err(error)
-    }
-}
\ No newline at end of file
@@ -1,84 +1,95 @@
-use serde::Deserialize;
+use serde::Serialize;
 
 use crate::api::grafana::GrafanaClient;
+use crate::api::preferences::Preferences;
+use crate::api::team::GetTeamResponse;
+use crate::cli::shell::output::{cell, OutputFormat, Tabular};
 use crate::cli::team::options::TeamOptions;
 use crate::error::GrafanaCliError;
 
-#[derive(Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SearchTeamsResponse {
-    pub total_count: u32,
-    pub teams: Vec<GetTeamResponse>,
-    pub page: u32,
-    pub per_page: u32,
-}
+impl Tabular for GetTeamResponse {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["ID", "NAME", "ORG_ID", "MEMBERS"];
+        if wide {
+            headers.extend(["EMAIL", "AVATAR_URL"]);
+        }
+        headers
+    }
 
-impl SearchTeamsResponse {
-    pub fn get_zero_member_teams(&self) -> Vec<GetTeamResponse> {
-        self.teams.iter().filter(|team| team.member_count == 0).cloned().collect()
+    fn row(&self, wide: bool) -> Vec<String> {
+        let mut row = vec![self.id.to_string(), self.name.clone(), self.org_id.to_string(), self.member_count.to_string()];
+        if wide {
+            row.extend([self.email.clone(), self.avatar_url.clone()]);
+        }
+        row
     }
 }
 
-#[derive(Deserialize, Clone)]
+/// A team together with the logins of its members
+#[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct GetTeamResponse {
-    pub id: u32,
-    pub org_id: u32,
-    pub name: String,
-    pub email: String,
-    pub avatar_url: String,
-    pub member_count: u32,
+pub struct TeamWithMembers {
+    #[serde(flatten)]
+    pub team: GetTeamResponse,
+    pub member_logins: Vec<String>,
 }
 
-pub async fn handle_get_team(grafana_client: &GrafanaClient, opt: &TeamOptions) {
-    if let Some(team_id) = opt.id {
-        match get_team_by_id(grafana_client, team_id).await {
-            Ok(response) => {
-                println!("Team:");
-                println!("id: {} | name: {} | org_id: {} | email: {}", response.id, response.name, response.org_id, response.email);
-                println!("avatar_url: {} | members: {}", response.avatar_url, response.member_count);
-                return;
-            }
-            Err(error) => {
-                eprintln!("{}", error);
-                return;
-            }
+impl Tabular for TeamWithMembers {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["ID", "NAME", "ORG_ID", "MEMBERS", "LOGINS"];
+        if wide {
+            headers.extend(["EMAIL", "AVATAR_URL"]);
         }
+        headers
     }
-    match get_teams(grafana_client, opt.query.clone()).await {
-        Ok(response) => {
-            println!("Teams ({}):", response.total_count);
-            for team in response.teams {
-                println!("id: {} | name: {} | org_id: {} | members: {}", team.id, team.name, team.org_id, team.member_count);
-            }
-        }
-        Err(error) => {
-            eprintln!("{}", error);
+
+    fn row(&self, wide: bool) -> Vec<String> {
+        let team = &self.team;
+        let logins = if self.member_logins.is_empty() { "-".to_string() } else { self.member_logins.join(",") };
+        let mut row = vec![team.id.to_string(), team.name.clone(), team.org_id.to_string(), team.member_count.to_string(), logins];
+        if wide {
+            row.extend([team.email.clone(), team.avatar_url.clone()]);
         }
+        row
     }
 }
 
-pub async fn get_team_by_id(grafana_client: &GrafanaClient, team_id: u32) -> Result<GetTeamResponse, GrafanaCliError> {
-    match grafana_client.get(&format!("teams/{}", team_id)).await {
-        Ok(response) => {
-            Ok(response.json::<GetTeamResponse>().await?)
-        }
-        Err(error) => {
-            Err(GrafanaCliError::Request(error))
-        }
+impl Tabular for Preferences {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["HOME_DASHBOARD_UID", "THEME", "TIMEZONE", "WEEK_START"]
+    }
+
+    fn row(&self, _wide: bool) -> Vec<String> {
+        // grafana responds with empty strings for preferences inherited from the organization
+        let value = |value: &Option<String>| cell(&value.clone().filter(|value| !value.is_empty()));
+        vec![value(&self.home_dashboard_uid), value(&self.theme), value(&self.timezone), value(&self.week_start)]
     }
 }
 
-pub async fn get_teams(grafana_client: &GrafanaClient, query: Option<String>) -> Result<SearchTeamsResponse, reqwest::Error> {
-    if let Some(name) = query {
-        match grafana_client.query("teams/search", &[("query", name)]).await {
-            Ok(response) => Ok(response.json::<SearchTeamsResponse>().await?),
-            Err(error) => Err(error)
-        }
-    } else {
-        match grafana_client.get("teams/search").await {
-            Ok(response) => Ok(response.json::<SearchTeamsResponse>().await?),
-            Err(error) => Err(error)
+pub async fn handle_get_team(grafana_client: &GrafanaClient, opt: &TeamOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    if let Some(team_id) = opt.id {
+        if opt.preferences {
+            let preferences = grafana_client.teams().preferences(team_id).await?;
+            return output.print_one(&preferences);
         }
+        let response = grafana_client.teams().get(team_id).await?;
+        return output.print_one(&with_members(grafana_client, response).await?);
     }
+    let teams = grafana_client.teams().search_all(opt.query.as_deref()).await?;
+    let mut teams_with_members = vec![];
+    for team in teams {
+        teams_with_members.push(with_members(grafana_client, team).await?);
+    }
+    output.message(format!("Teams ({}):", teams_with_members.len()));
+    output.print(&teams_with_members)
+}
+
+async fn with_members(grafana_client: &GrafanaClient, team: GetTeamResponse) -> Result<TeamWithMembers, GrafanaCliError> {
+    // teams without members are skipped to save a request per empty team
+    let member_logins = if team.member_count > 0 {
+        grafana_client.teams().members(team.id).await?.into_iter().map(|member| member.login).collect()
+    } else {
+        vec![]
+    };
+    Ok(TeamWithMembers { team, member_logins })
 }
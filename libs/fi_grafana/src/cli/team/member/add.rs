@@ -0,0 +1,28 @@
+use crate::api::grafana::GrafanaClient;
+use crate::cli::shell::input::prompt_option;
+use crate::cli::shell::output::OutputFormat;
+use crate::cli::team::member::options::TeamMemberOptions;
+use crate::error::GrafanaCliError;
+
+pub async fn handle_add_team_member(grafana_client: &GrafanaClient, opt: &TeamMemberOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let team_id = prompt_option("Enter the team id: ", &opt.team_id).ok_or(GrafanaCliError::CanNotManageTeamMembersWithoutTeamId)?;
+    let users = opt.users()?;
+    let mut added = 0;
+    for user in &users {
+        let result = match grafana_client.users().resolve_id(user).await {
+            Ok(user_id) => grafana_client.teams().add_member(team_id, user_id).await,
+            Err(error) => Err(error),
+        };
+        match result {
+            Ok(response) => {
+                added += 1;
+                output.message(format!("{} [team: {}, user: {}]", response.message, team_id, user));
+            }
+            Err(error) => {
+                eprintln!("{} [team: {}, user: {}]", error, team_id, user);
+            }
+        }
+    }
+    output.message(format!("Added {} of {} members", added, users.len()));
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use crate::api::grafana::GrafanaClient;
+use crate::api::team::GetTeamMemberResponse;
+use crate::cli::shell::input::prompt_option;
+use crate::cli::shell::output::{OutputFormat, Tabular};
+use crate::cli::team::member::options::TeamMemberOptions;
+use crate::error::GrafanaCliError;
+
+impl Tabular for GetTeamMemberResponse {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["USER_ID", "LOGIN", "EMAIL", "NAME"];
+        if wide {
+            headers.extend(["TEAM_ID", "PERMISSION"]);
+        }
+        headers
+    }
+
+    fn row(&self, wide: bool) -> Vec<String> {
+        let mut row = vec![self.user_id.to_string(), self.login.clone(), self.email.clone(), self.name.clone()];
+        if wide {
+            let permission = if self.permission == 4 { "admin" } else { "member" };
+            row.extend([self.team_id.to_string(), permission.to_string()]);
+        }
+        row
+    }
+}
+
+pub async fn handle_get_team_member(grafana_client: &GrafanaClient, opt: &TeamMemberOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let team_id = prompt_option("Enter the team id: ", &opt.team_id).ok_or(GrafanaCliError::CanNotManageTeamMembersWithoutTeamId)?;
+    let members = grafana_client.teams().members(team_id).await?;
+    output.message(format!("Team members ({}):", members.len()));
+    output.print(&members)
+}
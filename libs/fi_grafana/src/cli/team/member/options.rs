@@ -0,0 +1,39 @@
+use std::fs;
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::error::GrafanaCliError;
+
+/// The available options for team member requests
+#[derive(Debug, Args, Clone)]
+pub struct TeamMemberOptions {
+    /// Use it to get, add or delete the members of the team with this id
+    #[arg(short, long)]
+    pub team_id: Option<u32>,
+    /// Use it to add or delete this user by its id, login or email (repeatable)
+    #[arg(short, long)]
+    pub user: Vec<String>,
+    /// Use it to add or delete the users listed in this file (one id, login or email per line, # starts a comment)
+    #[arg(short, long)]
+    pub file: Option<PathBuf>,
+    /// Use it to confirm the deletion of the members upfront
+    #[arg(short, long, default_value_t = false)]
+    pub yes: bool,
+}
+
+impl TeamMemberOptions {
+    /// The users given by --user followed by the ones listed in the --file
+    pub fn users(&self) -> Result<Vec<String>, GrafanaCliError> {
+        let mut users = self.user.clone();
+        if let Some(file) = &self.file {
+            let content = fs::read_to_string(file)?;
+            users.extend(content
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_string));
+        }
+        Ok(users)
+    }
+}
@@ -1,4 +1,6 @@
 pub mod add;
 pub mod delete;
 pub mod get;
+pub mod member;
 pub mod options;
+pub mod set;
@@ -1,20 +1,19 @@
 use clap::Args;
 
+use crate::api::preferences::{Theme, WeekStart};
+
 /// The available options for team requests
 #[derive(Debug, Args, Clone)]
 pub struct TeamOptions {
-    /// Use it to get or delete a team by its id
+    /// Use it to get, set or delete a team by its id
     #[arg(short, long)]
     pub id: Option<u32>,
-    /// Use it to add a team with this name
+    /// Use it to add a team with this name or to rename a team
     #[arg(short, long)]
     pub name: Option<String>,
-    /// Use it to add a team with this email
+    /// Use it to add a team with this email or to change the email of a team
     #[arg(short, long)]
     pub email: Option<String>,
-    /// Use it to add a team to an existing organization with this id
-    #[arg(short, long)]
-    pub org_id: Option<u32>,
     /// Use it to specify a folder title when the --directory flag is present (otherwise the team name is used)
     #[arg(short, long)]
     pub folder_title: Option<String>,
@@ -30,4 +29,26 @@ pub struct TeamOptions {
     /// Use it to confirm the deletion of zero member teams upfront
     #[arg(short, long, default_value_t = false)]
     pub yes: bool,
-}
\ No newline at end of file
+    /// Use it to get the preferences of the team given by --id
+    #[arg(long, default_value_t = false)]
+    pub preferences: bool,
+    /// Use it to set the home dashboard of the team members
+    #[arg(long)]
+    pub home_dashboard_uid: Option<String>,
+    /// Use it to set the ui theme of the team members
+    #[arg(long, value_enum)]
+    pub theme: Option<Theme>,
+    /// Use it to set the timezone of the team members (utc, browser or an IANA time zone like Europe/Berlin)
+    #[arg(long)]
+    pub timezone: Option<String>,
+    /// Use it to set the first day of the week of the team members
+    #[arg(long, value_enum)]
+    pub week_start: Option<WeekStart>,
+}
+
+impl TeamOptions {
+    /// Whether any of the team preferences is given
+    pub fn has_preferences(&self) -> bool {
+        self.home_dashboard_uid.is_some() || self.theme.is_some() || self.timezone.is_some() || self.week_start.is_some()
+    }
+}
@@ -0,0 +1,39 @@
+use crate::api::grafana::GrafanaClient;
+use crate::api::preferences::Preferences;
+use crate::api::team::UpdateTeamRequest;
+use crate::cli::shell::input::prompt_option;
+use crate::cli::shell::output::OutputFormat;
+use crate::cli::team::options::TeamOptions;
+use crate::error::GrafanaCliError;
+
+pub async fn handle_set_team(grafana_client: &GrafanaClient, opt: &TeamOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let team_id = prompt_option("Enter the team id: ", &opt.id).ok_or(GrafanaCliError::CanNotSetTeamWithoutId)?;
+    if opt.name.is_none() && opt.email.is_none() && !opt.has_preferences() {
+        output.message("Nothing to set, use --name, --email or one of the preferences options");
+        return Ok(());
+    }
+    if opt.name.is_some() || opt.email.is_some() {
+        // grafana replaces both fields, so the one not given keeps its current value
+        let team = grafana_client.teams().get(team_id).await?;
+        let request = UpdateTeamRequest {
+            name: opt.name.clone().unwrap_or(team.name),
+            email: opt.email.clone().unwrap_or(team.email),
+        };
+        let response = grafana_client.teams().update(team_id, &request).await?;
+        output.message(format!("{} [id: {}, name: {}, email: {}]", response.message, team_id, request.name, request.email));
+    }
+    if opt.has_preferences() {
+        // grafana replaces all preferences, so the ones not given keep their current value
+        let current = grafana_client.teams().preferences(team_id).await?;
+        let preferences = Preferences {
+            theme: opt.theme.map(|theme| theme.as_str().to_string()).or(current.theme),
+            home_dashboard_uid: opt.home_dashboard_uid.clone().or(current.home_dashboard_uid),
+            timezone: opt.timezone.clone().or(current.timezone),
+            week_start: opt.week_start.map(|week_start| week_start.as_str().to_string()).or(current.week_start),
+        };
+        let response = grafana_client.teams().set_preferences(team_id, &preferences).await?;
+        output.message(format!("{} [id: {}]", response.message, team_id));
+        output.print_one(&preferences)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use crate::api::grafana::GrafanaClient;
+use crate::api::user::{AddUserRequest, AddUserResponse};
+use crate::cli::shell::input::prompt_option;
+use crate::cli::shell::output::{OutputFormat, Tabular};
+use crate::cli::user::options::UserOptions;
+use crate::error::GrafanaCliError;
+
+impl Tabular for AddUserResponse {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["ID", "MESSAGE"]
+    }
+
+    fn row(&self, _wide: bool) -> Vec<String> {
+        vec![self.id.to_string(), self.message.clone()]
+    }
+}
+
+pub async fn handle_add_user(grafana_client: &GrafanaClient, opt: &UserOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    if opt.login.is_none() && opt.email.is_none() {
+        return Err(GrafanaCliError::CanNotAddUserWithoutLoginOrEmail);
+    }
+    let password = prompt_option("Enter an initial password: ", &opt.initial_password).ok_or(GrafanaCliError::CanNotAddUserWithoutPassword)?;
+    let request = AddUserRequest {
+        name: opt.name.clone(),
+        email: opt.email.clone(),
+        login: opt.login.clone(),
+        password,
+        org_id: grafana_client.org_id,
+    };
+    let response = grafana_client.users().create(&request).await?;
+    output.print_one(&response)
+}
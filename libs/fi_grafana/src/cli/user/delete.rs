@@ -0,0 +1,17 @@
+use crate::api::grafana::GrafanaClient;
+use crate::cli::shell::input::{confirm, prompt_option};
+use crate::cli::shell::output::OutputFormat;
+use crate::cli::user::options::UserOptions;
+use crate::error::GrafanaCliError;
+
+pub async fn handle_del_user(grafana_client: &GrafanaClient, opt: &UserOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let user = prompt_option("Enter the user id, login or email: ", &opt.user).ok_or(GrafanaCliError::CanNotDeleteUserWithoutUser)?;
+    let user = grafana_client.users().find(&user).await?;
+    if confirm(&format!("Delete user [{}] with email [{}] from all organizations?", user.login, user.email), opt.yes) {
+        let response = grafana_client.users().delete(user.id).await?;
+        output.message(format!("{} [id: {}, login: {}]", response.message, user.id, user.login));
+    } else {
+        output.message("No delete confirmation. Skipping request..");
+    }
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use crate::api::grafana::GrafanaClient;
+use crate::api::page::DEFAULT_PAGE_SIZE;
+use crate::api::user::{GetUserResponse, SearchUserResponse, UserOrgResponse};
+use crate::cli::shell::output::{cell, OutputFormat, Tabular};
+use crate::cli::user::options::UserOptions;
+use crate::error::GrafanaCliError;
+
+impl Tabular for SearchUserResponse {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["ID", "LOGIN", "EMAIL", "NAME", "ADMIN", "DISABLED"];
+        if wide {
+            headers.push("LAST_SEEN");
+        }
+        headers
+    }
+
+    fn row(&self, wide: bool) -> Vec<String> {
+        let mut row = vec![
+            self.id.to_string(),
+            self.login.clone(),
+            self.email.clone(),
+            self.name.clone(),
+            self.is_admin.to_string(),
+            self.is_disabled.to_string(),
+        ];
+        if wide {
+            row.push(cell(&self.last_seen_at_age));
+        }
+        row
+    }
+}
+
+impl Tabular for GetUserResponse {
+    fn headers(wide: bool) -> Vec<&'static str> {
+        let mut headers = vec!["ID", "LOGIN", "EMAIL", "NAME", "ADMIN", "DISABLED"];
+        if wide {
+            headers.extend(["ORG_ID", "EXTERNAL", "CREATED", "UPDATED"]);
+        }
+        headers
+    }
+
+    fn row(&self, wide: bool) -> Vec<String> {
+        let mut row = vec![
+            self.id.to_string(),
+            self.login.clone(),
+            self.email.clone(),
+            self.name.clone(),
+            self.is_grafana_admin.to_string(),
+            self.is_disabled.to_string(),
+        ];
+        if wide {
+            row.extend([self.org_id.to_string(), self.is_external.to_string(), self.created_at.clone(), self.updated_at.clone()]);
+        }
+        row
+    }
+}
+
+impl Tabular for UserOrgResponse {
+    fn headers(_wide: bool) -> Vec<&'static str> {
+        vec!["ORG_ID", "NAME", "ROLE"]
+    }
+
+    fn row(&self, _wide: bool) -> Vec<String> {
+        vec![self.org_id.to_string(), self.name.clone(), self.role.clone()]
+    }
+}
+
+pub async fn handle_get_user(grafana_client: &GrafanaClient, opt: &UserOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    if let Some(user) = &opt.user {
+        let user = grafana_client.users().find(user).await?;
+        if opt.teams {
+            let teams = grafana_client.users().teams(user.id).await?;
+            output.message(format!("Teams of [{}] ({}):", user.login, teams.len()));
+            return output.print(&teams);
+        }
+        if opt.orgs {
+            let orgs = grafana_client.users().orgs(user.id).await?;
+            output.message(format!("Organizations of [{}] ({}):", user.login, orgs.len()));
+            return output.print(&orgs);
+        }
+        return output.print_one(&user);
+    }
+    let users = if opt.limit.is_some() || opt.page.is_some() {
+        let limit = opt.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        grafana_client.users().search_page(opt.query.as_deref(), opt.page.unwrap_or(1), limit).await?.users
+    } else {
+        grafana_client.users().search_all(opt.query.as_deref()).await?
+    };
+    output.message(format!("Users ({}):", users.len()));
+    output.print(&users)
+}
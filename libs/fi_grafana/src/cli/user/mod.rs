@@ -1,4 +1,5 @@
-use clap::Args;
-
-#[derive(Debug, Args, Clone)]
-pub struct User {}
\ No newline at end of file
+pub mod add;
+pub mod delete;
+pub mod get;
+pub mod options;
+pub mod set;
@@ -0,0 +1,53 @@
+use clap::Args;
+
+use crate::api::user::OrgRole;
+
+/// The available options for user requests
+#[derive(Debug, Args, Clone)]
+pub struct UserOptions {
+    /// Use it to get, set or delete a user by its id, login or email
+    #[arg(short, long)]
+    pub user: Option<String>,
+    /// Use it to get users whose login, email or name match the given query
+    #[arg(short, long)]
+    pub query: Option<String>,
+    /// Use it to limit the number of users per page (otherwise all pages are fetched)
+    #[arg(short, long)]
+    pub limit: Option<u32>,
+    /// Use it to get the users of only this page (otherwise all pages are fetched)
+    #[arg(short, long)]
+    pub page: Option<u32>,
+    /// Use it to add a user with this name or to change the name of a user
+    #[arg(short, long)]
+    pub name: Option<String>,
+    /// Use it to add a user with this email or to change the email of a user
+    #[arg(short, long)]
+    pub email: Option<String>,
+    /// Use it to add a user with this login or to change the login of a user
+    #[arg(long)]
+    pub login: Option<String>,
+    /// Use it to add a user with this password (prompted if missing)
+    #[arg(long)]
+    pub initial_password: Option<String>,
+    /// Use it to disable a user, disabled users can not log in
+    #[arg(long, default_value_t = false, conflicts_with = "enable")]
+    pub disable: bool,
+    /// Use it to enable a disabled user
+    #[arg(long, default_value_t = false)]
+    pub enable: bool,
+    /// Use it to grant (true) or revoke (false) the server wide grafana admin flag of a user
+    #[arg(long)]
+    pub grafana_admin: Option<bool>,
+    /// Use it to change the basic role of a user in the current organization
+    #[arg(long, value_enum)]
+    pub role: Option<OrgRole>,
+    /// Use it to get the teams of the user given by --user
+    #[arg(long, default_value_t = false)]
+    pub teams: bool,
+    /// Use it to get the organizations and roles of the user given by --user
+    #[arg(long, default_value_t = false)]
+    pub orgs: bool,
+    /// Use it to confirm the deletion of the user upfront
+    #[arg(short, long, default_value_t = false)]
+    pub yes: bool,
+}
@@ -0,0 +1,46 @@
+use crate::api::grafana::GrafanaClient;
+use crate::api::user::UpdateUserRequest;
+use crate::cli::shell::input::prompt_option;
+use crate::cli::shell::output::OutputFormat;
+use crate::cli::user::options::UserOptions;
+use crate::error::GrafanaCliError;
+
+pub async fn handle_set_user(grafana_client: &GrafanaClient, opt: &UserOptions, output: OutputFormat) -> Result<(), GrafanaCliError> {
+    let user = prompt_option("Enter the user id, login or email: ", &opt.user).ok_or(GrafanaCliError::CanNotSetUserWithoutUser)?;
+    let user = grafana_client.users().find(&user).await?;
+    let mut changed = false;
+    if opt.name.is_some() || opt.email.is_some() || opt.login.is_some() {
+        // grafana replaces all fields, so the ones not given keep their current value
+        let request = UpdateUserRequest {
+            name: opt.name.clone().unwrap_or(user.name.clone()),
+            email: opt.email.clone().unwrap_or(user.email.clone()),
+            login: opt.login.clone().unwrap_or(user.login.clone()),
+        };
+        let response = grafana_client.users().update(user.id, &request).await?;
+        output.message(format!("{} [id: {}, login: {}, email: {}, name: {}]", response.message, user.id, request.login, request.email, request.name));
+        changed = true;
+    }
+    if opt.disable || opt.enable {
+        let response = if opt.disable {
+            grafana_client.users().disable(user.id).await?
+        } else {
+            grafana_client.users().enable(user.id).await?
+        };
+        output.message(format!("{} [id: {}, login: {}]", response.message, user.id, user.login));
+        changed = true;
+    }
+    if let Some(is_grafana_admin) = opt.grafana_admin {
+        let response = grafana_client.users().set_grafana_admin(user.id, is_grafana_admin).await?;
+        output.message(format!("{} [id: {}, login: {}, grafana admin: {}]", response.message, user.id, user.login, is_grafana_admin));
+        changed = true;
+    }
+    if let Some(role) = opt.role {
+        let response = grafana_client.users().set_org_role(user.id, role).await?;
+        output.message(format!("{} [id: {}, login: {}, role: {:?}]", response.message, user.id, user.login, role));
+        changed = true;
+    }
+    if !changed {
+        output.message("Nothing to set, use --name, --email, --login, --disable, --enable, --grafana-admin or --role");
+    }
+    Ok(())
+}
@@ -1,8 +1,13 @@
+use crate::api::grafana::GrafanaErrorResponse;
+
 #[derive(Debug, thiserror::Error, displaydoc::Display)]
 pub enum GrafanaCliError {
     /// an I/O error occurred: {0}
     IO(#[from] std::io::Error),
 
+    /// a json error occurred: {0}
+    Json(#[from] serde_json::Error),
+
     /// a network error occurred: {0}
     Request(#[from] reqwest::Error),
 
@@ -15,6 +20,9 @@ pub enum GrafanaCliError {
     /// datetime parse error: {0}
     CanNotParseDateTimeToEpochTimeMillis(#[from] chrono::ParseError),
 
+    /// the datetime {0} does not exist in the local timezone as the clocks skip it
+    NonExistentLocalDateTime(String),
+
     /// can not parse the start_datetime to epoch time millis [format: %Y-%m-%d %H:%M]
     CanNotParseTheStartDateTimeToEpochTimeMillis,
 
@@ -27,6 +35,184 @@ pub enum GrafanaCliError {
     /// can not add a folder without a title
     CanNotAddFolderWithoutTitle,
 
+    /// can not set a folder without its uid
+    CanNotSetFolderWithoutUid,
+
+    /// can not delete a folder without its uid
+    CanNotDeleteFolderWithoutUid,
+
+    /// can not add an annotation without a comment
+    CanNotAddAnnotationWithoutComment,
+
+    /// can not set a team without an id
+    CanNotSetTeamWithoutId,
+
+    /// can not add a user without a login or email
+    CanNotAddUserWithoutLoginOrEmail,
+
+    /// can not add a user without a password
+    CanNotAddUserWithoutPassword,
+
+    /// can not set a user without its id, login or email
+    CanNotSetUserWithoutUser,
+
+    /// can not delete a user without its id, login or email
+    CanNotDeleteUserWithoutUser,
+
+    /// can not add a service account without a name
+    CanNotAddServiceAccountWithoutName,
+
+    /// can not set a service account without an id
+    CanNotSetServiceAccountWithoutId,
+
+    /// can not delete a service account without an id
+    CanNotDeleteServiceAccountWithoutId,
+
+    /// can not manage tokens without a service account id
+    CanNotManageTokensWithoutServiceAccountId,
+
+    /// can not assign or unassign a role without its uid
+    CanNotAssignRoleWithoutUid,
+
+    /// can not set a role without a yaml file defining it
+    CanNotSetRoleWithoutFile,
+
+    /// can not set a role without its uid (use --uid or a uid in the file)
+    CanNotSetRoleWithoutUid,
+
+    /// can not delete a role without its uid
+    CanNotDeleteRoleWithoutUid,
+
+    /// invalid role file {0}: {1}
+    InvalidRoleFile(String, String),
+
+    /// no team found with the name {0}
+    TeamNotFound(String),
+
+    /// can not grant permissions without a permission level (use --permission view|edit|admin)
+    CanNotGrantPermissionsWithoutLevel,
+
+    /// can not set permissions without a team, user or role (use --reset to replace all entries by the defaults)
+    CanNotSetPermissionsWithoutGrantees,
+
+    /// can not manage team members without a team id
+    CanNotManageTeamMembersWithoutTeamId,
+
+    /// can not rotate the tokens of a service account without --older-than-days (0 deletes all but the new token)
+    CanNotRotateTokensWithoutAge,
+
+    /// more annotations share the time {0} than grafana returns at once, the result would be incomplete
+    IncompleteAnnotations(i64),
+
+    /// can not set an annotation without an id
+    CanNotSetAnnotationWithoutId,
+
+    /// can not delete annotations without an id or at least one filter (tags, time range, dashboard or folder scope)
+    CanNotDeleteAnnotationsWithoutFilter,
+
+    /// {0} of {1} annotations failed, see the errors above
+    SomeAnnotationsFailed(usize, usize),
+
+    /// dry run stopped at {0}, the following requests depend on its response
+    DryRunResponseRequired(String),
+
     /// can not encode url params to string: {0}
     CanNotEncodeUrlParamToString(#[from] serde_url_params::Error),
-}
\ No newline at end of file
+
+    /// no grafana api path given, use --api, the GRAFANA_API_PATH env var or a profile with an url
+    MissingGrafanaApiPath,
+
+    /// no grafana credentials given, use --token, --api-key, --username with --password or a profile with auth
+    MissingGrafanaCredentials,
+
+    /// the profile [{0}] is not defined in the config file
+    ProfileNotFound(String),
+
+    /// the config file is invalid: {0}
+    InvalidConfigFile(#[from] serde_yaml::Error),
+
+    /// the secret command [{0}] failed: {1}
+    SecretCommandFailed(String, String),
+
+    /// the dashboard file {0} is invalid: {1}
+    InvalidDashboardFile(String, String),
+
+    /// can not set a dashboard without its uid
+    CanNotSetDashboardWithoutUid,
+
+    /// nothing to set on the dashboard, use --restore or --move-to
+    NothingToSetOnDashboard,
+
+    /// can not delete dashboards without a uid, query, tag or folder uid
+    CanNotDeleteDashboardsWithoutFilter,
+
+    /// {0} of {1} dashboards failed, see the errors above
+    SomeDashboardsFailed(usize, usize),
+
+    /// can not migrate to the same grafana instance and organization [{0}], check the url and org_id of the --from and --to profiles
+    CanNotMigrateToTheSameInstance(String),
+
+    /// {0} of {1} resources failed to migrate, see the report above
+    SomeMigrationsFailed(usize, usize),
+
+    /// no folder found with the uid or title {0}
+    FolderNotFound(String),
+
+    /// can not render the output: {0}
+    CanNotRenderOutput(String),
+
+    /// unauthorized, check the credentials of the grafana client [401]: {0}
+    Unauthorized(String),
+
+    /// forbidden, the grafana client lacks the permission for this request [403]: {0}
+    Forbidden(String),
+
+    /// not found [404]: {0}
+    NotFound(String),
+
+    /// conflict with an existing resource [409]: {0}
+    Conflict(String),
+
+    /// precondition failed, the resource has been changed by someone else [412]: {0}
+    PreconditionFailed(String),
+
+    /// grafana rejected the request [{status}]: {message}
+    BadRequest { status: u16, message: String },
+
+    /// grafana server error [{status}]: {message}
+    ServerError { status: u16, message: String },
+}
+
+impl GrafanaCliError {
+    /// Maps a non-successful http status and grafana's error body onto a typed error
+    pub fn from_status(status: u16, error: GrafanaErrorResponse) -> Self {
+        let message = match error.status {
+            Some(reason) => format!("{} ({})", error.message, reason),
+            None => error.message,
+        };
+        match status {
+            401 => GrafanaCliError::Unauthorized(message),
+            403 => GrafanaCliError::Forbidden(message),
+            404 => GrafanaCliError::NotFound(message),
+            409 => GrafanaCliError::Conflict(message),
+            412 => GrafanaCliError::PreconditionFailed(message),
+            500.. => GrafanaCliError::ServerError { status, message },
+            _ => GrafanaCliError::BadRequest { status, message },
+        }
+    }
+
+    /// The process exit code scripts can branch on (2 is reserved for cli usage errors)
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            GrafanaCliError::Unauthorized(_) => 3,
+            GrafanaCliError::Forbidden(_) => 4,
+            GrafanaCliError::NotFound(_) => 5,
+            GrafanaCliError::Conflict(_) => 6,
+            GrafanaCliError::PreconditionFailed(_) => 7,
+            GrafanaCliError::BadRequest { .. } => 8,
+            GrafanaCliError::ServerError { .. } => 9,
+            GrafanaCliError::Request(_) => 10,
+            _ => 1,
+        }
+    }
+}
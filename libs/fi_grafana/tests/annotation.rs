@@ -0,0 +1,70 @@
+mod stub;
+
+use std::collections::HashSet;
+
+use fi_grafana::api::annotation::FindAnnotationsRequest;
+use fi_grafana::api::grafana::{GrafanaAuth, GrafanaClient};
+use serde_json::{json, Value};
+
+use stub::{StubRequest, StubResponse, StubServer};
+
+/// Answers like grafana with the annotations in the time range, newest first, cut at the limit
+async fn server_with(times: Vec<i64>) -> StubServer {
+    StubServer::start(move |request| {
+        let from = query_param(request, "from").unwrap_or(i64::MIN);
+        let to = query_param(request, "to").unwrap_or(i64::MAX);
+        let limit = query_param(request, "limit").unwrap_or(100) as usize;
+        let mut annotations: Vec<(usize, i64)> = times.iter().copied().enumerate().filter(|(_, time)| (from..=to).contains(time)).collect();
+        annotations.sort_by_key(|(id, time)| (-time, *id));
+        let body: Vec<Value> = annotations.into_iter().take(limit).map(|(id, time)| annotation(id + 1, time)).collect();
+        StubResponse::json(200, &Value::Array(body).to_string())
+    }).await
+}
+
+fn query_param(request: &StubRequest, name: &str) -> Option<i64> {
+    let (_, query) = request.path.split_once('?')?;
+    query.split('&').filter_map(|pair| pair.split_once('=')).find(|(key, _)| *key == name).and_then(|(_, value)| value.parse().ok())
+}
+
+fn annotation(id: usize, time: i64) -> Value {
+    json!({
+        "id": id, "alertId": 0, "dashboardId": 0, "dashboardUID": null, "panelId": 0, "userId": 1,
+        "login": "admin", "email": "admin@localhost", "newState": "", "prevState": "",
+        "created": time, "updated": time, "time": time, "timeEnd": time, "text": "deploy", "tags": []
+    })
+}
+
+async fn find_all(server: &StubServer) -> Vec<u32> {
+    let client = GrafanaClient::new(reqwest::Client::new(), server.api.clone(), GrafanaAuth::ServiceAccountToken("token".to_string()));
+    let annotations = client.annotations().find_all(&FindAnnotationsRequest::default()).await.unwrap();
+    annotations.into_iter().map(|annotation| annotation.id).collect()
+}
+
+fn assert_unique(ids: &[u32], expected: usize) {
+    assert_eq!(ids.len(), expected);
+    assert_eq!(ids.iter().collect::<HashSet<_>>().len(), expected);
+}
+
+#[tokio::test]
+async fn pages_are_joined_without_duplicates() {
+    let times = (0..2500).map(|index| 10_000 - index / 3).collect();
+    let server = server_with(times).await;
+    assert_unique(&find_all(&server).await, 2500);
+}
+
+#[tokio::test]
+async fn annotations_sharing_the_boundary_time_are_not_truncated() {
+    let mut times = vec![1000; 1500];
+    times.extend(vec![500; 50]);
+    let server = server_with(times).await;
+    assert_unique(&find_all(&server).await, 1550);
+}
+
+#[tokio::test]
+async fn annotations_sharing_the_boundary_time_on_a_later_page_are_not_truncated() {
+    let mut times: Vec<i64> = (0..600).map(|index| 5000 - index).collect();
+    times.extend(vec![1000; 4500]);
+    times.extend(vec![10; 3]);
+    let server = server_with(times).await;
+    assert_unique(&find_all(&server).await, 5103);
+}
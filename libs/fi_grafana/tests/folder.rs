@@ -0,0 +1,62 @@
+mod stub;
+
+use fi_grafana::api::grafana::{GrafanaAuth, GrafanaClient};
+use fi_grafana::cli::folder::get::{get_folder_tree, FolderNode};
+use serde_json::{json, Value};
+
+use stub::{StubRequest, StubResponse, StubServer};
+
+/// The folders as (uid, parent uid), nested ones are only answered when grafana supports nested folders
+const FOLDERS: [(&str, Option<&str>); 4] = [("a", None), ("b", None), ("a1", Some("a")), ("a2", Some("a"))];
+
+/// Answers the first page of the folders below the parent, the rest of the pages are empty
+async fn server(nested_folders: bool) -> StubServer {
+    StubServer::start(move |request| {
+        if !request.path.contains("page=1") {
+            return StubResponse::json(200, "[]");
+        }
+        let parent_uid = nested_folders.then(|| parent_uid(request)).flatten();
+        let folders: Vec<Value> = FOLDERS
+            .iter()
+            .filter(|(_, parent)| parent.map(str::to_string) == parent_uid)
+            .map(|(uid, _)| json!({"id": 1, "uid": uid, "title": uid.to_uppercase()}))
+            .collect();
+        StubResponse::json(200, &Value::Array(folders).to_string())
+    }).await
+}
+
+fn parent_uid(request: &StubRequest) -> Option<String> {
+    let (_, query) = request.path.split_once('?')?;
+    query.split('&').find_map(|pair| pair.strip_prefix("parentUid=")).map(str::to_string)
+}
+
+fn client(server: &StubServer) -> GrafanaClient {
+    GrafanaClient::new(reqwest::Client::new(), server.api.clone(), GrafanaAuth::ServiceAccountToken("token".to_string()))
+}
+
+fn uids(nodes: &[FolderNode]) -> Vec<(String, Vec<String>)> {
+    nodes.iter().map(|node| (node.uid.clone(), node.children.iter().map(|child| child.uid.clone()).collect())).collect()
+}
+
+#[tokio::test]
+async fn tree_nests_the_subfolders() {
+    let server = server(true).await;
+    let tree = get_folder_tree(&client(&server), None).await.unwrap();
+    assert_eq!(uids(&tree), vec![("a".to_string(), vec!["a1".to_string(), "a2".to_string()]), ("b".to_string(), vec![])]);
+}
+
+#[tokio::test]
+async fn tree_below_a_folder_has_its_subfolders() {
+    let server = server(true).await;
+    let tree = get_folder_tree(&client(&server), Some("a".to_string())).await.unwrap();
+    assert_eq!(uids(&tree), vec![("a1".to_string(), vec![]), ("a2".to_string(), vec![])]);
+}
+
+#[tokio::test]
+async fn root_level_echo_without_nested_folders_is_no_subfolder() {
+    let server = server(false).await;
+    let client = client(&server);
+    let tree = get_folder_tree(&client, None).await.unwrap();
+    assert_eq!(uids(&tree), vec![("a".to_string(), vec![]), ("b".to_string(), vec![])]);
+    assert!(get_folder_tree(&client, Some("a".to_string())).await.unwrap().is_empty());
+}
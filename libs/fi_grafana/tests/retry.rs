@@ -0,0 +1,129 @@
+mod stub;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use fi_grafana::api::grafana::{GrafanaAuth, GrafanaClient};
+use fi_grafana::api::retry::RetryPolicy;
+use fi_grafana::error::GrafanaCliError;
+use serde_json::Value;
+
+use stub::{StubResponse, StubServer};
+
+const MAX_BACKOFF: Duration = Duration::from_millis(50);
+
+/// Answers with the given statuses in order and with 200 once they are used up
+async fn server_responding(statuses: Vec<(u16, Option<&'static str>)>) -> StubServer {
+    let count = AtomicUsize::new(0);
+    StubServer::start(move |_| {
+        let index = count.fetch_add(1, Ordering::SeqCst);
+        match statuses.get(index) {
+            Some((status, retry_after)) => {
+                let response = StubResponse::json(*status, r#"{"message":"try again"}"#);
+                match retry_after {
+                    Some(retry_after) => response.header("Retry-After", retry_after),
+                    None => response,
+                }
+            }
+            None => StubResponse::json(200, r#"{"message":"ok"}"#),
+        }
+    }).await
+}
+
+/// A client with three attempts and short delays which records the retries
+fn client(server: &StubServer) -> (GrafanaClient, Arc<Mutex<Vec<Duration>>>) {
+    let delays = Arc::new(Mutex::new(Vec::new()));
+    let recorded = delays.clone();
+    let client = GrafanaClient::new(reqwest::Client::new(), server.api.clone(), GrafanaAuth::ServiceAccountToken("token".to_string()))
+        .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(1), MAX_BACKOFF))
+        .with_retry_listener(Some(Arc::new(move |retry| recorded.lock().unwrap().push(retry.delay))));
+    (client, delays)
+}
+
+#[tokio::test]
+async fn too_many_requests_is_retried_until_success() {
+    let server = server_responding(vec![(429, None), (429, None)]).await;
+    let (client, delays) = client(&server);
+    let response: Value = client.get("teams/1").await.unwrap();
+    assert_eq!(response["message"], "ok");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(requests.iter().all(|request| request.path == "/api/teams/1"));
+    assert_eq!(delays.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn too_many_requests_is_retried_for_post() {
+    let server = server_responding(vec![(429, None)]).await;
+    let (client, _) = client(&server);
+    let response: Value = client.post("teams", &serde_json::json!({"name": "ops"})).await.unwrap();
+    assert_eq!(response["message"], "ok");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests.iter().all(|request| request.method == "POST" && request.body.contains("ops")));
+}
+
+#[tokio::test]
+async fn service_unavailable_is_retried_for_get() {
+    let server = server_responding(vec![(503, None)]).await;
+    let (client, _) = client(&server);
+    let _: Value = client.get("folders").await.unwrap();
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn internal_server_error_is_retried_for_get() {
+    let server = server_responding(vec![(500, None)]).await;
+    let (client, _) = client(&server);
+    let _: Value = client.get("folders").await.unwrap();
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn service_unavailable_is_not_retried_for_post() {
+    let server = server_responding(vec![(503, None)]).await;
+    let (client, delays) = client(&server);
+    let result: Result<Value, GrafanaCliError> = client.post("folders", &serde_json::json!({"title": "f"})).await;
+    assert!(matches!(result, Err(GrafanaCliError::ServerError { status: 503, .. })));
+    assert_eq!(server.requests().len(), 1);
+    assert!(delays.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn retries_stop_after_the_max_attempts() {
+    let server = server_responding(vec![(503, None); 5]).await;
+    let (client, _) = client(&server);
+    let result: Result<Value, GrafanaCliError> = client.get("folders").await;
+    assert!(matches!(result, Err(GrafanaCliError::ServerError { status: 503, .. })));
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn retry_after_is_capped_by_the_max_backoff() {
+    let server = server_responding(vec![(503, Some("3600"))]).await;
+    let (client, delays) = client(&server);
+    let started = Instant::now();
+    let _: Value = client.get("folders").await.unwrap();
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert_eq!(*delays.lock().unwrap(), vec![MAX_BACKOFF]);
+}
+
+#[tokio::test]
+async fn retry_after_below_the_max_backoff_is_kept() {
+    let server = server_responding(vec![(429, Some("0"))]).await;
+    let (client, delays) = client(&server);
+    let _: Value = client.get("folders").await.unwrap();
+    assert_eq!(*delays.lock().unwrap(), vec![Duration::ZERO]);
+}
+
+#[test]
+fn backoff_is_between_half_and_the_whole_capped_delay() {
+    let policy = RetryPolicy::new(10, Duration::from_millis(100), Duration::from_millis(1000));
+    for (attempt, capped) in [(1, 100), (2, 200), (3, 400), (4, 800), (5, 1000), (9, 1000)] {
+        for _ in 0..50 {
+            let backoff = policy.backoff(attempt).as_millis();
+            assert!((capped / 2..=capped).contains(&backoff), "attempt {} waited {}ms", attempt, backoff);
+        }
+    }
+}